@@ -0,0 +1,119 @@
+//! Throughput of concurrent searches with a shared client versus a fresh
+//! client (and connection pool) per query.
+//!
+//! Run with `cargo bench --bench concurrent_search`.
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use serper_sdk::SearchQuery;
+use serper_sdk::core::types::{ApiKey, BaseUrl};
+use serper_sdk::http::{SerperHttpClient, TransportConfig};
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+use tokio::sync::Semaphore;
+
+const QUERIES: usize = 50;
+const MAX_CONCURRENT: usize = 10;
+
+fn response_body() -> String {
+    serde_json::json!({
+        "organic": [
+            {"title": "Bench Result", "link": "https://bench.example.com", "position": 1}
+        ]
+    })
+    .to_string()
+}
+
+fn queries() -> Vec<SearchQuery> {
+    (0..QUERIES)
+        .map(|i| SearchQuery::new(format!("bench query {}", i)).unwrap())
+        .collect()
+}
+
+/// Runs the queries concurrently, cloning one shared client into each task
+async fn run_shared(client: &SerperHttpClient, queries: &[SearchQuery]) {
+    let semaphore = Arc::new(Semaphore::new(MAX_CONCURRENT));
+    let handles: Vec<_> = queries
+        .iter()
+        .cloned()
+        .map(|query| {
+            let client = client.clone();
+            let semaphore = Arc::clone(&semaphore);
+            tokio::spawn(async move {
+                let _permit = semaphore.acquire().await.unwrap();
+                client.search(&query).await.unwrap();
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.await.unwrap();
+    }
+}
+
+/// Runs the queries concurrently, building a new client for each task
+async fn run_fresh(base_url: &str, queries: &[SearchQuery]) {
+    let semaphore = Arc::new(Semaphore::new(MAX_CONCURRENT));
+    let handles: Vec<_> = queries
+        .iter()
+        .cloned()
+        .map(|query| {
+            let base_url = BaseUrl::new(base_url.to_string());
+            let semaphore = Arc::clone(&semaphore);
+            tokio::spawn(async move {
+                let _permit = semaphore.acquire().await.unwrap();
+                let client = SerperHttpClient::with_config(
+                    ApiKey::new("bench-key".to_string()).unwrap(),
+                    base_url,
+                    TransportConfig::default(),
+                )
+                .unwrap();
+                client.search(&query).await.unwrap();
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.await.unwrap();
+    }
+}
+
+fn concurrent_search(c: &mut Criterion) {
+    let runtime = Runtime::new().unwrap();
+    let (server, _mock) = runtime.block_on(async {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/search")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(response_body())
+            .expect_at_least(0)
+            .create_async()
+            .await;
+        (server, mock)
+    });
+
+    let base_url = server.url();
+    let shared = SerperHttpClient::with_config(
+        ApiKey::new("bench-key".to_string()).unwrap(),
+        BaseUrl::new(base_url.clone()),
+        TransportConfig::default(),
+    )
+    .unwrap();
+    let queries = queries();
+
+    let mut group = c.benchmark_group("concurrent_search");
+    group.throughput(Throughput::Elements(QUERIES as u64));
+
+    group.bench_function(BenchmarkId::new("shared_pool", QUERIES), |b| {
+        b.to_async(&runtime).iter(|| run_shared(&shared, &queries));
+    });
+
+    group.bench_function(BenchmarkId::new("client_per_query", QUERIES), |b| {
+        b.to_async(&runtime).iter(|| run_fresh(&base_url, &queries));
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, concurrent_search);
+criterion_main!(benches);
@@ -26,7 +26,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let service = SearchService::new(config.api_key)?;
 
     // Create queries for different searches
-    let queries = vec![
+    let queries = [
         SearchQuery::new("Hamze Ghalebi CTO at Remolab".to_string())?
             .with_country("us".to_string())
             .with_language("en".to_string()),
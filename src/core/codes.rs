@@ -0,0 +1,445 @@
+//! Embedded country and language code tables
+//!
+//! This module contains the ISO 3166-1 alpha-2 country codes accepted by
+//! Google's `gl` parameter and the interface languages accepted by `hl`.
+
+/// Country codes (ISO 3166-1 alpha-2, plus Google's `uk` alias) and names, sorted by code
+pub(crate) const COUNTRIES: &[(&str, &str)] = &[
+    ("ad", "Andorra"),
+    ("ae", "United Arab Emirates"),
+    ("af", "Afghanistan"),
+    ("ag", "Antigua and Barbuda"),
+    ("ai", "Anguilla"),
+    ("al", "Albania"),
+    ("am", "Armenia"),
+    ("ao", "Angola"),
+    ("aq", "Antarctica"),
+    ("ar", "Argentina"),
+    ("as", "American Samoa"),
+    ("at", "Austria"),
+    ("au", "Australia"),
+    ("aw", "Aruba"),
+    ("ax", "Åland Islands"),
+    ("az", "Azerbaijan"),
+    ("ba", "Bosnia and Herzegovina"),
+    ("bb", "Barbados"),
+    ("bd", "Bangladesh"),
+    ("be", "Belgium"),
+    ("bf", "Burkina Faso"),
+    ("bg", "Bulgaria"),
+    ("bh", "Bahrain"),
+    ("bi", "Burundi"),
+    ("bj", "Benin"),
+    ("bl", "Saint Barthélemy"),
+    ("bm", "Bermuda"),
+    ("bn", "Brunei Darussalam"),
+    ("bo", "Bolivia"),
+    ("bq", "Bonaire, Sint Eustatius and Saba"),
+    ("br", "Brazil"),
+    ("bs", "Bahamas"),
+    ("bt", "Bhutan"),
+    ("bv", "Bouvet Island"),
+    ("bw", "Botswana"),
+    ("by", "Belarus"),
+    ("bz", "Belize"),
+    ("ca", "Canada"),
+    ("cc", "Cocos (Keeling) Islands"),
+    ("cd", "Congo, The Democratic Republic of the"),
+    ("cf", "Central African Republic"),
+    ("cg", "Congo"),
+    ("ch", "Switzerland"),
+    ("ci", "Côte d'Ivoire"),
+    ("ck", "Cook Islands"),
+    ("cl", "Chile"),
+    ("cm", "Cameroon"),
+    ("cn", "China"),
+    ("co", "Colombia"),
+    ("cr", "Costa Rica"),
+    ("cu", "Cuba"),
+    ("cv", "Cabo Verde"),
+    ("cw", "Curaçao"),
+    ("cx", "Christmas Island"),
+    ("cy", "Cyprus"),
+    ("cz", "Czechia"),
+    ("de", "Germany"),
+    ("dj", "Djibouti"),
+    ("dk", "Denmark"),
+    ("dm", "Dominica"),
+    ("do", "Dominican Republic"),
+    ("dz", "Algeria"),
+    ("ec", "Ecuador"),
+    ("ee", "Estonia"),
+    ("eg", "Egypt"),
+    ("eh", "Western Sahara"),
+    ("er", "Eritrea"),
+    ("es", "Spain"),
+    ("et", "Ethiopia"),
+    ("fi", "Finland"),
+    ("fj", "Fiji"),
+    ("fk", "Falkland Islands (Malvinas)"),
+    ("fm", "Micronesia, Federated States of"),
+    ("fo", "Faroe Islands"),
+    ("fr", "France"),
+    ("ga", "Gabon"),
+    ("gb", "United Kingdom"),
+    ("gd", "Grenada"),
+    ("ge", "Georgia"),
+    ("gf", "French Guiana"),
+    ("gg", "Guernsey"),
+    ("gh", "Ghana"),
+    ("gi", "Gibraltar"),
+    ("gl", "Greenland"),
+    ("gm", "Gambia"),
+    ("gn", "Guinea"),
+    ("gp", "Guadeloupe"),
+    ("gq", "Equatorial Guinea"),
+    ("gr", "Greece"),
+    ("gs", "South Georgia and the South Sandwich Islands"),
+    ("gt", "Guatemala"),
+    ("gu", "Guam"),
+    ("gw", "Guinea-Bissau"),
+    ("gy", "Guyana"),
+    ("hk", "Hong Kong"),
+    ("hm", "Heard Island and McDonald Islands"),
+    ("hn", "Honduras"),
+    ("hr", "Croatia"),
+    ("ht", "Haiti"),
+    ("hu", "Hungary"),
+    ("id", "Indonesia"),
+    ("ie", "Ireland"),
+    ("il", "Israel"),
+    ("im", "Isle of Man"),
+    ("in", "India"),
+    ("io", "British Indian Ocean Territory"),
+    ("iq", "Iraq"),
+    ("ir", "Iran"),
+    ("is", "Iceland"),
+    ("it", "Italy"),
+    ("je", "Jersey"),
+    ("jm", "Jamaica"),
+    ("jo", "Jordan"),
+    ("jp", "Japan"),
+    ("ke", "Kenya"),
+    ("kg", "Kyrgyzstan"),
+    ("kh", "Cambodia"),
+    ("ki", "Kiribati"),
+    ("km", "Comoros"),
+    ("kn", "Saint Kitts and Nevis"),
+    ("kp", "North Korea"),
+    ("kr", "South Korea"),
+    ("kw", "Kuwait"),
+    ("ky", "Cayman Islands"),
+    ("kz", "Kazakhstan"),
+    ("la", "Laos"),
+    ("lb", "Lebanon"),
+    ("lc", "Saint Lucia"),
+    ("li", "Liechtenstein"),
+    ("lk", "Sri Lanka"),
+    ("lr", "Liberia"),
+    ("ls", "Lesotho"),
+    ("lt", "Lithuania"),
+    ("lu", "Luxembourg"),
+    ("lv", "Latvia"),
+    ("ly", "Libya"),
+    ("ma", "Morocco"),
+    ("mc", "Monaco"),
+    ("md", "Moldova"),
+    ("me", "Montenegro"),
+    ("mf", "Saint Martin (French part)"),
+    ("mg", "Madagascar"),
+    ("mh", "Marshall Islands"),
+    ("mk", "North Macedonia"),
+    ("ml", "Mali"),
+    ("mm", "Myanmar"),
+    ("mn", "Mongolia"),
+    ("mo", "Macao"),
+    ("mp", "Northern Mariana Islands"),
+    ("mq", "Martinique"),
+    ("mr", "Mauritania"),
+    ("ms", "Montserrat"),
+    ("mt", "Malta"),
+    ("mu", "Mauritius"),
+    ("mv", "Maldives"),
+    ("mw", "Malawi"),
+    ("mx", "Mexico"),
+    ("my", "Malaysia"),
+    ("mz", "Mozambique"),
+    ("na", "Namibia"),
+    ("nc", "New Caledonia"),
+    ("ne", "Niger"),
+    ("nf", "Norfolk Island"),
+    ("ng", "Nigeria"),
+    ("ni", "Nicaragua"),
+    ("nl", "Netherlands"),
+    ("no", "Norway"),
+    ("np", "Nepal"),
+    ("nr", "Nauru"),
+    ("nu", "Niue"),
+    ("nz", "New Zealand"),
+    ("om", "Oman"),
+    ("pa", "Panama"),
+    ("pe", "Peru"),
+    ("pf", "French Polynesia"),
+    ("pg", "Papua New Guinea"),
+    ("ph", "Philippines"),
+    ("pk", "Pakistan"),
+    ("pl", "Poland"),
+    ("pm", "Saint Pierre and Miquelon"),
+    ("pn", "Pitcairn"),
+    ("pr", "Puerto Rico"),
+    ("ps", "Palestine, State of"),
+    ("pt", "Portugal"),
+    ("pw", "Palau"),
+    ("py", "Paraguay"),
+    ("qa", "Qatar"),
+    ("re", "Réunion"),
+    ("ro", "Romania"),
+    ("rs", "Serbia"),
+    ("ru", "Russian Federation"),
+    ("rw", "Rwanda"),
+    ("sa", "Saudi Arabia"),
+    ("sb", "Solomon Islands"),
+    ("sc", "Seychelles"),
+    ("sd", "Sudan"),
+    ("se", "Sweden"),
+    ("sg", "Singapore"),
+    ("sh", "Saint Helena, Ascension and Tristan da Cunha"),
+    ("si", "Slovenia"),
+    ("sj", "Svalbard and Jan Mayen"),
+    ("sk", "Slovakia"),
+    ("sl", "Sierra Leone"),
+    ("sm", "San Marino"),
+    ("sn", "Senegal"),
+    ("so", "Somalia"),
+    ("sr", "Suriname"),
+    ("ss", "South Sudan"),
+    ("st", "Sao Tome and Principe"),
+    ("sv", "El Salvador"),
+    ("sx", "Sint Maarten (Dutch part)"),
+    ("sy", "Syria"),
+    ("sz", "Eswatini"),
+    ("tc", "Turks and Caicos Islands"),
+    ("td", "Chad"),
+    ("tf", "French Southern Territories"),
+    ("tg", "Togo"),
+    ("th", "Thailand"),
+    ("tj", "Tajikistan"),
+    ("tk", "Tokelau"),
+    ("tl", "Timor-Leste"),
+    ("tm", "Turkmenistan"),
+    ("tn", "Tunisia"),
+    ("to", "Tonga"),
+    ("tr", "Türkiye"),
+    ("tt", "Trinidad and Tobago"),
+    ("tv", "Tuvalu"),
+    ("tw", "Taiwan"),
+    ("tz", "Tanzania"),
+    ("ua", "Ukraine"),
+    ("ug", "Uganda"),
+    ("uk", "United Kingdom"),
+    ("um", "United States Minor Outlying Islands"),
+    ("us", "United States"),
+    ("uy", "Uruguay"),
+    ("uz", "Uzbekistan"),
+    ("va", "Holy See (Vatican City State)"),
+    ("vc", "Saint Vincent and the Grenadines"),
+    ("ve", "Venezuela"),
+    ("vg", "Virgin Islands, British"),
+    ("vi", "Virgin Islands, U.S."),
+    ("vn", "Vietnam"),
+    ("vu", "Vanuatu"),
+    ("wf", "Wallis and Futuna"),
+    ("ws", "Samoa"),
+    ("ye", "Yemen"),
+    ("yt", "Mayotte"),
+    ("za", "South Africa"),
+    ("zm", "Zambia"),
+    ("zw", "Zimbabwe"),
+];
+
+/// Google-supported interface language codes and names, sorted by code
+pub(crate) const LANGUAGES: &[(&str, &str)] = &[
+    ("af", "Afrikaans"),
+    ("ak", "Akan"),
+    ("am", "Amharic"),
+    ("ar", "Arabic"),
+    ("az", "Azerbaijani"),
+    ("be", "Belarusian"),
+    ("bg", "Bulgarian"),
+    ("bn", "Bengali"),
+    ("br", "Breton"),
+    ("bs", "Bosnian"),
+    ("ca", "Catalan"),
+    ("ceb", "Cebuano"),
+    ("ckb", "Kurdish (Sorani)"),
+    ("co", "Corsican"),
+    ("cs", "Czech"),
+    ("cy", "Welsh"),
+    ("da", "Danish"),
+    ("de", "German"),
+    ("ee", "Ewe"),
+    ("el", "Greek"),
+    ("en", "English"),
+    ("eo", "Esperanto"),
+    ("es", "Spanish"),
+    ("es-419", "Spanish (Latin America)"),
+    ("et", "Estonian"),
+    ("eu", "Basque"),
+    ("fa", "Persian"),
+    ("fi", "Finnish"),
+    ("fil", "Filipino"),
+    ("fo", "Faroese"),
+    ("fr", "French"),
+    ("fy", "Frisian"),
+    ("ga", "Irish"),
+    ("gd", "Scots Gaelic"),
+    ("gl", "Galician"),
+    ("gn", "Guarani"),
+    ("gu", "Gujarati"),
+    ("ha", "Hausa"),
+    ("haw", "Hawaiian"),
+    ("he", "Hebrew"),
+    ("hi", "Hindi"),
+    ("hr", "Croatian"),
+    ("ht", "Haitian Creole"),
+    ("hu", "Hungarian"),
+    ("hy", "Armenian"),
+    ("ia", "Interlingua"),
+    ("id", "Indonesian"),
+    ("ig", "Igbo"),
+    ("is", "Icelandic"),
+    ("it", "Italian"),
+    ("iw", "Hebrew"),
+    ("ja", "Japanese"),
+    ("jw", "Javanese"),
+    ("ka", "Georgian"),
+    ("kk", "Kazakh"),
+    ("km", "Khmer"),
+    ("kn", "Kannada"),
+    ("ko", "Korean"),
+    ("ku", "Kurdish"),
+    ("ky", "Kyrgyz"),
+    ("la", "Latin"),
+    ("lb", "Luxembourgish"),
+    ("lg", "Luganda"),
+    ("ln", "Lingala"),
+    ("lo", "Lao"),
+    ("lt", "Lithuanian"),
+    ("lv", "Latvian"),
+    ("mg", "Malagasy"),
+    ("mi", "Maori"),
+    ("mk", "Macedonian"),
+    ("ml", "Malayalam"),
+    ("mn", "Mongolian"),
+    ("mr", "Marathi"),
+    ("ms", "Malay"),
+    ("mt", "Maltese"),
+    ("my", "Burmese"),
+    ("ne", "Nepali"),
+    ("nl", "Dutch"),
+    ("nn", "Norwegian (Nynorsk)"),
+    ("no", "Norwegian"),
+    ("ny", "Chichewa"),
+    ("oc", "Occitan"),
+    ("om", "Oromo"),
+    ("or", "Odia"),
+    ("pa", "Punjabi"),
+    ("pl", "Polish"),
+    ("ps", "Pashto"),
+    ("pt", "Portuguese"),
+    ("pt-br", "Portuguese (Brazil)"),
+    ("pt-pt", "Portuguese (Portugal)"),
+    ("qu", "Quechua"),
+    ("rm", "Romansh"),
+    ("rn", "Kirundi"),
+    ("ro", "Romanian"),
+    ("ru", "Russian"),
+    ("rw", "Kinyarwanda"),
+    ("sd", "Sindhi"),
+    ("si", "Sinhala"),
+    ("sk", "Slovak"),
+    ("sl", "Slovenian"),
+    ("sm", "Samoan"),
+    ("sn", "Shona"),
+    ("so", "Somali"),
+    ("sq", "Albanian"),
+    ("sr", "Serbian"),
+    ("st", "Sesotho"),
+    ("su", "Sundanese"),
+    ("sv", "Swedish"),
+    ("sw", "Swahili"),
+    ("ta", "Tamil"),
+    ("te", "Telugu"),
+    ("tg", "Tajik"),
+    ("th", "Thai"),
+    ("ti", "Tigrinya"),
+    ("tk", "Turkmen"),
+    ("tl", "Tagalog"),
+    ("tn", "Setswana"),
+    ("to", "Tongan"),
+    ("tr", "Turkish"),
+    ("tt", "Tatar"),
+    ("tw", "Twi"),
+    ("ug", "Uyghur"),
+    ("uk", "Ukrainian"),
+    ("ur", "Urdu"),
+    ("uz", "Uzbek"),
+    ("vi", "Vietnamese"),
+    ("wo", "Wolof"),
+    ("xh", "Xhosa"),
+    ("yi", "Yiddish"),
+    ("yo", "Yoruba"),
+    ("zh", "Chinese"),
+    ("zh-cn", "Chinese (Simplified)"),
+    ("zh-tw", "Chinese (Traditional)"),
+    ("zu", "Zulu"),
+];
+
+/// Looks up a code in a sorted table, returning its name
+pub(crate) fn lookup(
+    table: &'static [(&'static str, &'static str)],
+    code: &str,
+) -> Option<&'static str> {
+    table
+        .binary_search_by(|(candidate, _)| (*candidate).cmp(code))
+        .ok()
+        .map(|idx| table[idx].1)
+}
+
+/// Finds the code whose name matches the given name, ignoring case
+pub(crate) fn code_for_name(
+    table: &'static [(&'static str, &'static str)],
+    name: &str,
+) -> Option<&'static str> {
+    let name = name.trim();
+    table
+        .iter()
+        .find(|(_, candidate)| candidate.eq_ignore_ascii_case(name))
+        .map(|(code, _)| *code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tables_are_sorted() {
+        assert!(COUNTRIES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert!(LANGUAGES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn test_lookup() {
+        assert_eq!(lookup(COUNTRIES, "fr"), Some("France"));
+        assert_eq!(lookup(LANGUAGES, "pt-br"), Some("Portuguese (Brazil)"));
+        assert_eq!(lookup(COUNTRIES, "france"), None);
+    }
+
+    #[test]
+    fn test_code_for_name() {
+        assert_eq!(code_for_name(COUNTRIES, "France"), Some("fr"));
+        assert_eq!(code_for_name(COUNTRIES, "united kingdom"), Some("gb"));
+        assert_eq!(code_for_name(LANGUAGES, "german"), Some("de"));
+        assert_eq!(code_for_name(LANGUAGES, "Klingon"), None);
+    }
+}
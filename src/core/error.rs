@@ -2,6 +2,7 @@
 ///
 /// This module defines all error types that can occur within the SDK,
 /// providing comprehensive error handling with detailed context.
+use std::time::Duration;
 use thiserror::Error;
 
 /// Main error type for the Serper SDK
@@ -23,6 +24,12 @@ pub enum SerperError {
     #[error("JSON parsing failed: {0}")]
     Json(#[from] serde_json::Error),
 
+    /// I/O operation failed
+    ///
+    /// This error occurs when reading or writing files fails
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
     /// API returned an error response
     ///
     /// This error represents HTTP error status codes and API-specific errors
@@ -32,6 +39,66 @@ pub enum SerperError {
         message: String,
     },
 
+    /// The API returned an HTTP error status
+    ///
+    /// `body` keeps the raw response body; Serper's JSON error message is
+    /// included in the display text when present.
+    #[error("HTTP {status}{}", format_http_detail(*.status, .body))]
+    Http {
+        /// HTTP status code
+        status: u16,
+        /// Response body, if any
+        body: Option<String>,
+        /// Request identifier from the `x-request-id` response header, if any
+        request_id: Option<String>,
+    },
+
+    /// The API rejected the request because of rate limiting (HTTP 429)
+    ///
+    /// `retry_after` is parsed from the `Retry-After` response header
+    #[error("Rate limited{}", format_retry_after(.retry_after))]
+    RateLimited {
+        /// How long the API asked to wait before retrying
+        retry_after: Option<Duration>,
+    },
+
+    /// The circuit breaker is open after repeated transient failures
+    ///
+    /// The request was not sent; it can be retried once the breaker's open
+    /// duration has elapsed.
+    #[error("Circuit breaker is open; request not sent")]
+    CircuitOpen,
+
+    /// The credit budget is used up; the request was not sent
+    #[error("Credit budget of {limit} exhausted{}", format_resets_in(.resets_in))]
+    BudgetExceeded {
+        /// Credits allowed per budget window
+        limit: u64,
+        /// Time until a windowed budget starts over, if it has windows
+        resets_in: Option<Duration>,
+    },
+
+    /// The request was cancelled through its cancellation token
+    #[error("Request was cancelled")]
+    Cancelled,
+
+    /// The request did not complete within its timeout
+    #[error("Request timed out after {after:?}")]
+    Timeout {
+        /// The timeout that elapsed
+        after: Duration,
+    },
+
+    /// Every key in the API key pool is rejected or out of credits
+    #[error("All {keys} API keys in the pool are exhausted{}", format_last_error(.last_error))]
+    KeyPoolExhausted {
+        /// Number of keys in the pool
+        keys: usize,
+        /// Error of the key exhausted by this call, if it exhausted the last one
+        #[source]
+        last_error: Option<Box<SerperError>>,
+    },
+
     /// Invalid API key provided
     ///
     /// This error occurs when the API key is empty, malformed, or rejected
@@ -96,10 +163,225 @@ impl SerperError {
 
     /// Checks if the error is an API error
     pub fn is_api_error(&self) -> bool {
-        matches!(self, SerperError::Api { .. })
+        matches!(self, SerperError::Api { .. } | SerperError::Http { .. })
+    }
+
+    /// Gets the HTTP status code of an error response, if any
+    pub fn status_code(&self) -> Option<u16> {
+        match self {
+            SerperError::Http { status, .. } => Some(*status),
+            SerperError::RateLimited { .. } => Some(429),
+            _ => None,
+        }
+    }
+
+    /// Gets the error message returned by the API, if any
+    ///
+    /// For JSON bodies this is the `message` (or `error`) field; otherwise
+    /// the trimmed body text.
+    pub fn api_message(&self) -> Option<String> {
+        match self {
+            SerperError::Http { body, .. } => body.as_deref().and_then(extract_api_message),
+            SerperError::Api { message } => Some(message.clone()),
+            _ => None,
+        }
+    }
+
+    /// Checks if the error is a rate limit (HTTP 429) error
+    pub fn is_rate_limited(&self) -> bool {
+        matches!(self, SerperError::RateLimited { .. })
+    }
+
+    /// Gets the wait advised by the API before retrying, if any
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            SerperError::RateLimited { retry_after } => *retry_after,
+            _ => None,
+        }
+    }
+
+    /// Checks if the request was rejected by an open circuit breaker
+    pub fn is_circuit_open(&self) -> bool {
+        matches!(self, SerperError::CircuitOpen)
+    }
+
+    /// Checks if the API reported that the account is out of credits
+    ///
+    /// Serper reports this as an HTTP 400 or 402 error with a message such as
+    /// `Not enough credits`. Other messages that merely mention credits, such
+    /// as a 400 about an invalid parameter, do not count.
+    pub fn is_out_of_credits(&self) -> bool {
+        matches!(
+            self,
+            SerperError::Http {
+                status: 400 | 402,
+                ..
+            }
+        ) && self.api_message().is_some_and(|message| {
+            let message = message.to_lowercase();
+            OUT_OF_CREDITS_MESSAGES
+                .iter()
+                .any(|phrase| message.contains(phrase))
+        })
+    }
+
+    /// Checks if the request was rejected by an exhausted credit budget
+    pub fn is_budget_exceeded(&self) -> bool {
+        matches!(self, SerperError::BudgetExceeded { .. })
+    }
+
+    /// Checks if every key in the API key pool is exhausted
+    pub fn is_key_pool_exhausted(&self) -> bool {
+        matches!(self, SerperError::KeyPoolExhausted { .. })
+    }
+
+    /// Checks if the request was cancelled by the caller
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self, SerperError::Cancelled)
+    }
+
+    /// Checks if the request timed out
+    pub fn is_timeout(&self) -> bool {
+        match self {
+            SerperError::Timeout { .. } => true,
+            SerperError::Request(error) => error.is_timeout(),
+            _ => false,
+        }
+    }
+
+    /// Checks if the error is a transient failure worth retrying
+    ///
+    /// Connection failures, timeouts and HTTP 429/5xx responses are transient;
+    /// authentication, validation and parsing errors are not. An open circuit
+    /// is not retried, since the breaker already rejects requests.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            SerperError::RateLimited { .. } | SerperError::Timeout { .. } => true,
+            SerperError::Http { status, .. } => is_transient_status(*status),
+            SerperError::Request(error) => {
+                error.is_connect()
+                    || error.is_timeout()
+                    || error
+                        .status()
+                        .is_some_and(|status| is_transient_status(status.as_u16()))
+            }
+            _ => false,
+        }
+    }
+
+    /// Copies the error for another caller affected by the same failure
+    ///
+    /// Variants holding only plain data are copied exactly. Network, JSON and
+    /// I/O errors cannot be cloned and become an `Api` error with the same
+    /// message.
+    pub(crate) fn duplicate(&self) -> SerperError {
+        match self {
+            SerperError::Api { message } => SerperError::Api {
+                message: message.clone(),
+            },
+            SerperError::Http {
+                status,
+                body,
+                request_id,
+            } => SerperError::Http {
+                status: *status,
+                body: body.clone(),
+                request_id: request_id.clone(),
+            },
+            SerperError::RateLimited { retry_after } => SerperError::RateLimited {
+                retry_after: *retry_after,
+            },
+            SerperError::CircuitOpen => SerperError::CircuitOpen,
+            SerperError::BudgetExceeded { limit, resets_in } => SerperError::BudgetExceeded {
+                limit: *limit,
+                resets_in: *resets_in,
+            },
+            SerperError::Cancelled => SerperError::Cancelled,
+            SerperError::Timeout { after } => SerperError::Timeout { after: *after },
+            SerperError::KeyPoolExhausted { keys, last_error } => SerperError::KeyPoolExhausted {
+                keys: *keys,
+                last_error: last_error.as_ref().map(|error| Box::new(error.duplicate())),
+            },
+            SerperError::InvalidApiKey => SerperError::InvalidApiKey,
+            SerperError::Config { message } => SerperError::Config {
+                message: message.clone(),
+            },
+            SerperError::Validation { message } => SerperError::Validation {
+                message: message.clone(),
+            },
+            SerperError::Request(_) | SerperError::Json(_) | SerperError::Io(_) => {
+                SerperError::api_error(self.to_string())
+            }
+        }
+    }
+}
+
+/// Lowercase phrases of API messages reporting that the account is out of credits
+const OUT_OF_CREDITS_MESSAGES: &[&str] = &["not enough credits", "out of credits"];
+
+/// Formats the reason phrase and API message of an HTTP error
+fn format_http_detail(status: u16, body: &Option<String>) -> String {
+    let reason = reqwest::StatusCode::from_u16(status)
+        .ok()
+        .and_then(|status| status.canonical_reason());
+    let message = body.as_deref().and_then(extract_api_message);
+
+    match (reason, message) {
+        (Some(reason), Some(message)) => format!(" {}: {}", reason, message),
+        (Some(reason), None) => format!(" {}", reason),
+        (None, Some(message)) => format!(": {}", message),
+        (None, None) => String::new(),
+    }
+}
+
+/// Extracts the error message from an API error body
+fn extract_api_message(body: &str) -> Option<String> {
+    let body = body.trim();
+    if body.is_empty() {
+        return None;
+    }
+
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(body) else {
+        return Some(body.to_string());
+    };
+
+    let error = &json["error"];
+    [&json["message"], &error["message"], error]
+        .into_iter()
+        .find_map(|value| value.as_str())
+        .map(str::to_string)
+        .or_else(|| Some(body.to_string()))
+}
+
+/// Formats the optional retry delay of a rate limit error
+fn format_retry_after(retry_after: &Option<Duration>) -> String {
+    match retry_after {
+        Some(delay) => format!(" (retry after {}s)", delay.as_secs()),
+        None => String::new(),
+    }
+}
+
+/// Formats when a windowed credit budget starts over
+fn format_resets_in(resets_in: &Option<Duration>) -> String {
+    match resets_in {
+        Some(delay) => format!(" (resets in {}s)", delay.as_secs()),
+        None => String::new(),
     }
 }
 
+/// Formats the error of the key exhausted last
+fn format_last_error(last_error: &Option<Box<SerperError>>) -> String {
+    match last_error {
+        Some(error) => format!(" (last error: {})", error),
+        None => String::new(),
+    }
+}
+
+/// Checks if an HTTP status code indicates a transient failure (429 or 5xx)
+pub(crate) fn is_transient_status(status: u16) -> bool {
+    status == 429 || (500..600).contains(&status)
+}
+
 /// Type alias for Results using SerperError
 pub type Result<T> = std::result::Result<T, SerperError>;
 
@@ -157,6 +439,13 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_io_error_conversion() {
+        let io_error = std::io::Error::new(std::io::ErrorKind::NotFound, "missing.jsonl");
+        let serper_error: SerperError = io_error.into();
+        assert_eq!(serper_error.to_string(), "I/O error: missing.jsonl");
+    }
+
     #[test]
     fn test_error_variants() {
         let api_key_error = SerperError::InvalidApiKey;
@@ -200,4 +489,157 @@ mod tests {
         assert!(parse_error.is_parse_error());
         assert!(api_error.is_api_error());
     }
+
+    #[test]
+    fn test_retryable_classification() {
+        assert!(!SerperError::InvalidApiKey.is_retryable());
+        assert!(!SerperError::validation_error("Empty query").is_retryable());
+        assert!(is_transient_status(429));
+        assert!(is_transient_status(503));
+        assert!(!is_transient_status(400));
+        assert!(!is_transient_status(404));
+        assert!(!SerperError::CircuitOpen.is_retryable());
+        assert!(SerperError::CircuitOpen.is_circuit_open());
+    }
+
+    #[test]
+    fn test_http_error_display() {
+        let error = SerperError::Http {
+            status: 400,
+            body: Some(r#"{"message": "Not enough credits", "statusCode": 400}"#.to_string()),
+            request_id: Some("req-1".to_string()),
+        };
+        assert_eq!(
+            error.to_string(),
+            "HTTP 400 Bad Request: Not enough credits"
+        );
+        assert_eq!(error.status_code(), Some(400));
+        assert_eq!(error.api_message().as_deref(), Some("Not enough credits"));
+        assert!(error.is_api_error());
+        assert!(!error.is_retryable());
+        assert!(error.is_out_of_credits());
+
+        let error = SerperError::Http {
+            status: 402,
+            body: Some(r#"{"message": "Not enough credits"}"#.to_string()),
+            request_id: None,
+        };
+        assert!(error.is_out_of_credits());
+
+        let error = SerperError::Http {
+            status: 400,
+            body: Some(r#"{"message": "invalid credits param"}"#.to_string()),
+            request_id: None,
+        };
+        assert!(!error.is_out_of_credits());
+
+        // 403 is an authentication failure, whatever the message says
+        let error = SerperError::Http {
+            status: 403,
+            body: Some(r#"{"message": "Not enough credits"}"#.to_string()),
+            request_id: None,
+        };
+        assert!(!error.is_out_of_credits());
+
+        let error = SerperError::Http {
+            status: 502,
+            body: Some("  upstream unavailable \n".to_string()),
+            request_id: None,
+        };
+        assert_eq!(
+            error.to_string(),
+            "HTTP 502 Bad Gateway: upstream unavailable"
+        );
+        assert!(error.is_retryable());
+        assert!(!error.is_out_of_credits());
+
+        let error = SerperError::Http {
+            status: 500,
+            body: None,
+            request_id: None,
+        };
+        assert_eq!(error.to_string(), "HTTP 500 Internal Server Error");
+        assert_eq!(error.api_message(), None);
+    }
+
+    #[test]
+    fn test_extract_api_message() {
+        assert_eq!(
+            extract_api_message(r#"{"error": {"message": "Bad query"}}"#).as_deref(),
+            Some("Bad query")
+        );
+        assert_eq!(
+            extract_api_message(r#"{"error": "Bad query"}"#).as_deref(),
+            Some("Bad query")
+        );
+        assert_eq!(
+            extract_api_message(r#"{"detail": 1}"#).as_deref(),
+            Some(r#"{"detail": 1}"#)
+        );
+        assert_eq!(extract_api_message("   "), None);
+    }
+
+    #[test]
+    fn test_key_pool_exhausted_error() {
+        let error = SerperError::KeyPoolExhausted {
+            keys: 2,
+            last_error: Some(Box::new(SerperError::InvalidApiKey)),
+        };
+        assert_eq!(
+            error.to_string(),
+            "All 2 API keys in the pool are exhausted (last error: Invalid API key)"
+        );
+        assert!(error.is_key_pool_exhausted());
+        assert!(matches!(
+            error.duplicate(),
+            SerperError::KeyPoolExhausted {
+                keys: 2,
+                last_error: Some(last),
+            } if last.is_auth_error()
+        ));
+
+        let error = SerperError::KeyPoolExhausted {
+            keys: 2,
+            last_error: None,
+        };
+        assert_eq!(
+            error.to_string(),
+            "All 2 API keys in the pool are exhausted"
+        );
+    }
+
+    #[test]
+    fn test_rate_limited_error() {
+        let error = SerperError::RateLimited {
+            retry_after: Some(Duration::from_secs(30)),
+        };
+        assert_eq!(error.to_string(), "Rate limited (retry after 30s)");
+        assert!(error.is_rate_limited());
+        assert!(error.is_retryable());
+        assert_eq!(error.retry_after(), Some(Duration::from_secs(30)));
+
+        let error = SerperError::RateLimited { retry_after: None };
+        assert_eq!(error.to_string(), "Rate limited");
+        assert_eq!(error.retry_after(), None);
+    }
+
+    #[test]
+    fn test_budget_exceeded_error() {
+        let error = SerperError::BudgetExceeded {
+            limit: 100,
+            resets_in: Some(Duration::from_secs(60)),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Credit budget of 100 exhausted (resets in 60s)"
+        );
+        assert!(error.is_budget_exceeded());
+        assert!(!error.is_retryable());
+
+        let error = SerperError::BudgetExceeded {
+            limit: 100,
+            resets_in: None,
+        };
+        assert_eq!(error.to_string(), "Credit budget of 100 exhausted");
+    }
 }
@@ -0,0 +1,394 @@
+//! Embedded canonical location table
+//!
+//! This module contains an offline subset of Google's canonical location names
+//! (the values accepted by the `location` parameter) and the logic used by
+//! [`Location::resolve`](crate::core::types::Location::resolve) to match
+//! free-form input against them.
+
+/// Canonical location names and their country codes
+///
+/// Entries are ordered by prominence so that ambiguous names such as "Paris"
+/// or "London" resolve to the best-known place first.
+pub(crate) const LOCATIONS: &[(&str, &str)] = &[
+    ("New York,New York,United States", "us"),
+    ("Los Angeles,California,United States", "us"),
+    ("Chicago,Illinois,United States", "us"),
+    ("Houston,Texas,United States", "us"),
+    ("Phoenix,Arizona,United States", "us"),
+    ("Philadelphia,Pennsylvania,United States", "us"),
+    ("San Antonio,Texas,United States", "us"),
+    ("San Diego,California,United States", "us"),
+    ("Dallas,Texas,United States", "us"),
+    ("Austin,Texas,United States", "us"),
+    ("San Jose,California,United States", "us"),
+    ("San Francisco,California,United States", "us"),
+    ("Seattle,Washington,United States", "us"),
+    ("Denver,Colorado,United States", "us"),
+    ("Boston,Massachusetts,United States", "us"),
+    ("Washington,District of Columbia,United States", "us"),
+    ("Miami,Florida,United States", "us"),
+    ("Atlanta,Georgia,United States", "us"),
+    ("Las Vegas,Nevada,United States", "us"),
+    ("Portland,Oregon,United States", "us"),
+    ("Nashville,Tennessee,United States", "us"),
+    ("Detroit,Michigan,United States", "us"),
+    ("Minneapolis,Minnesota,United States", "us"),
+    ("New Orleans,Louisiana,United States", "us"),
+    ("London,England,United Kingdom", "gb"),
+    ("Manchester,England,United Kingdom", "gb"),
+    ("Birmingham,England,United Kingdom", "gb"),
+    ("Liverpool,England,United Kingdom", "gb"),
+    ("Leeds,England,United Kingdom", "gb"),
+    ("Bristol,England,United Kingdom", "gb"),
+    ("Edinburgh,Scotland,United Kingdom", "gb"),
+    ("Glasgow,Scotland,United Kingdom", "gb"),
+    ("Cardiff,Wales,United Kingdom", "gb"),
+    ("Belfast,Northern Ireland,United Kingdom", "gb"),
+    ("Dublin,County Dublin,Ireland", "ie"),
+    ("Paris,Paris,Ile-de-France,France", "fr"),
+    (
+        "Marseille,Bouches-du-Rhone,Provence-Alpes-Cote d'Azur,France",
+        "fr",
+    ),
+    ("Lyon,Rhone,Auvergne-Rhone-Alpes,France", "fr"),
+    ("Toulouse,Haute-Garonne,Occitanie,France", "fr"),
+    (
+        "Nice,Alpes-Maritimes,Provence-Alpes-Cote d'Azur,France",
+        "fr",
+    ),
+    ("Nantes,Loire-Atlantique,Pays de la Loire,France", "fr"),
+    ("Strasbourg,Bas-Rhin,Grand Est,France", "fr"),
+    ("Bordeaux,Gironde,Nouvelle-Aquitaine,France", "fr"),
+    ("Lille,Nord,Hauts-de-France,France", "fr"),
+    ("Berlin,Berlin,Germany", "de"),
+    ("Hamburg,Hamburg,Germany", "de"),
+    ("Munich,Bavaria,Germany", "de"),
+    ("Cologne,North Rhine-Westphalia,Germany", "de"),
+    ("Frankfurt,Hesse,Germany", "de"),
+    ("Stuttgart,Baden-Wurttemberg,Germany", "de"),
+    ("Dusseldorf,North Rhine-Westphalia,Germany", "de"),
+    ("Vienna,Vienna,Austria", "at"),
+    ("Zurich,Zurich,Switzerland", "ch"),
+    ("Geneva,Geneva,Switzerland", "ch"),
+    ("Bern,Bern,Switzerland", "ch"),
+    ("Amsterdam,North Holland,Netherlands", "nl"),
+    ("Rotterdam,South Holland,Netherlands", "nl"),
+    ("Brussels,Brussels,Belgium", "be"),
+    ("Antwerp,Flanders,Belgium", "be"),
+    ("Luxembourg,Luxembourg District,Luxembourg", "lu"),
+    ("Madrid,Community of Madrid,Spain", "es"),
+    ("Barcelona,Catalonia,Spain", "es"),
+    ("Valencia,Valencian Community,Spain", "es"),
+    ("Seville,Andalusia,Spain", "es"),
+    ("Lisbon,Lisbon,Portugal", "pt"),
+    ("Porto,Porto District,Portugal", "pt"),
+    ("Rome,Lazio,Italy", "it"),
+    ("Milan,Lombardy,Italy", "it"),
+    ("Naples,Campania,Italy", "it"),
+    ("Turin,Piedmont,Italy", "it"),
+    ("Florence,Tuscany,Italy", "it"),
+    ("Athens,Attica,Greece", "gr"),
+    ("Stockholm,Stockholm County,Sweden", "se"),
+    ("Oslo,Oslo,Norway", "no"),
+    ("Copenhagen,Capital Region of Denmark,Denmark", "dk"),
+    ("Helsinki,Uusimaa,Finland", "fi"),
+    ("Reykjavik,Capital Region,Iceland", "is"),
+    ("Warsaw,Masovian Voivodeship,Poland", "pl"),
+    ("Krakow,Lesser Poland Voivodeship,Poland", "pl"),
+    ("Prague,Prague,Czechia", "cz"),
+    ("Budapest,Budapest,Hungary", "hu"),
+    ("Bucharest,Bucharest,Romania", "ro"),
+    ("Sofia,Sofia City Province,Bulgaria", "bg"),
+    ("Kyiv,Kyiv city,Ukraine", "ua"),
+    ("Moscow,Moscow,Russia", "ru"),
+    ("Saint Petersburg,Saint Petersburg,Russia", "ru"),
+    ("Istanbul,Istanbul,Turkiye", "tr"),
+    ("Ankara,Ankara,Turkiye", "tr"),
+    ("Toronto,Ontario,Canada", "ca"),
+    ("Montreal,Quebec,Canada", "ca"),
+    ("Vancouver,British Columbia,Canada", "ca"),
+    ("Calgary,Alberta,Canada", "ca"),
+    ("Ottawa,Ontario,Canada", "ca"),
+    ("London,Ontario,Canada", "ca"),
+    ("Paris,Texas,United States", "us"),
+    ("Mexico City,Mexico City,Mexico", "mx"),
+    ("Guadalajara,Jalisco,Mexico", "mx"),
+    ("Sao Paulo,State of Sao Paulo,Brazil", "br"),
+    ("Rio de Janeiro,State of Rio de Janeiro,Brazil", "br"),
+    ("Buenos Aires,Buenos Aires,Argentina", "ar"),
+    ("Santiago,Santiago Metropolitan Region,Chile", "cl"),
+    ("Bogota,Bogota,Colombia", "co"),
+    ("Lima,Lima Region,Peru", "pe"),
+    ("Tokyo,Tokyo,Japan", "jp"),
+    ("Osaka,Osaka,Japan", "jp"),
+    ("Seoul,Seoul,South Korea", "kr"),
+    ("Beijing,Beijing,China", "cn"),
+    ("Shanghai,Shanghai,China", "cn"),
+    ("Hong Kong,Hong Kong", "hk"),
+    ("Taipei,Taipei City,Taiwan", "tw"),
+    ("Singapore,Singapore", "sg"),
+    ("Bangkok,Bangkok,Thailand", "th"),
+    (
+        "Kuala Lumpur,Federal Territory of Kuala Lumpur,Malaysia",
+        "my",
+    ),
+    ("Jakarta,Jakarta,Indonesia", "id"),
+    ("Manila,Metro Manila,Philippines", "ph"),
+    ("Ho Chi Minh City,Ho Chi Minh City,Vietnam", "vn"),
+    ("Mumbai,Maharashtra,India", "in"),
+    ("Delhi,Delhi,India", "in"),
+    ("Bengaluru,Karnataka,India", "in"),
+    ("Dubai,Dubai,United Arab Emirates", "ae"),
+    ("Riyadh,Riyadh Province,Saudi Arabia", "sa"),
+    ("Tel Aviv-Yafo,Tel Aviv District,Israel", "il"),
+    ("Cairo,Cairo Governorate,Egypt", "eg"),
+    ("Lagos,Lagos,Nigeria", "ng"),
+    ("Nairobi,Nairobi County,Kenya", "ke"),
+    ("Johannesburg,Gauteng,South Africa", "za"),
+    ("Cape Town,Western Cape,South Africa", "za"),
+    ("Casablanca,Grand Casablanca,Morocco", "ma"),
+    ("Sydney,New South Wales,Australia", "au"),
+    ("Melbourne,Victoria,Australia", "au"),
+    ("Brisbane,Queensland,Australia", "au"),
+    ("Perth,Western Australia,Australia", "au"),
+    ("Auckland,Auckland,New Zealand", "nz"),
+    ("United States", "us"),
+    ("United Kingdom", "gb"),
+    ("Ireland", "ie"),
+    ("France", "fr"),
+    ("Germany", "de"),
+    ("Austria", "at"),
+    ("Switzerland", "ch"),
+    ("Netherlands", "nl"),
+    ("Belgium", "be"),
+    ("Spain", "es"),
+    ("Portugal", "pt"),
+    ("Italy", "it"),
+    ("Greece", "gr"),
+    ("Sweden", "se"),
+    ("Norway", "no"),
+    ("Denmark", "dk"),
+    ("Finland", "fi"),
+    ("Poland", "pl"),
+    ("Czechia", "cz"),
+    ("Hungary", "hu"),
+    ("Romania", "ro"),
+    ("Ukraine", "ua"),
+    ("Russia", "ru"),
+    ("Turkiye", "tr"),
+    ("Canada", "ca"),
+    ("Mexico", "mx"),
+    ("Brazil", "br"),
+    ("Argentina", "ar"),
+    ("Chile", "cl"),
+    ("Colombia", "co"),
+    ("Peru", "pe"),
+    ("Japan", "jp"),
+    ("South Korea", "kr"),
+    ("China", "cn"),
+    ("Taiwan", "tw"),
+    ("Thailand", "th"),
+    ("Malaysia", "my"),
+    ("Indonesia", "id"),
+    ("Philippines", "ph"),
+    ("Vietnam", "vn"),
+    ("India", "in"),
+    ("United Arab Emirates", "ae"),
+    ("Saudi Arabia", "sa"),
+    ("Israel", "il"),
+    ("Egypt", "eg"),
+    ("Nigeria", "ng"),
+    ("Kenya", "ke"),
+    ("South Africa", "za"),
+    ("Morocco", "ma"),
+    ("Australia", "au"),
+    ("New Zealand", "nz"),
+];
+
+/// US state and district abbreviations, lowercased, with their names
+const US_STATES: &[(&str, &str)] = &[
+    ("al", "Alabama"),
+    ("ak", "Alaska"),
+    ("az", "Arizona"),
+    ("ar", "Arkansas"),
+    ("ca", "California"),
+    ("co", "Colorado"),
+    ("ct", "Connecticut"),
+    ("de", "Delaware"),
+    ("dc", "District of Columbia"),
+    ("fl", "Florida"),
+    ("ga", "Georgia"),
+    ("hi", "Hawaii"),
+    ("id", "Idaho"),
+    ("il", "Illinois"),
+    ("in", "Indiana"),
+    ("ia", "Iowa"),
+    ("ks", "Kansas"),
+    ("ky", "Kentucky"),
+    ("la", "Louisiana"),
+    ("me", "Maine"),
+    ("md", "Maryland"),
+    ("ma", "Massachusetts"),
+    ("mi", "Michigan"),
+    ("mn", "Minnesota"),
+    ("ms", "Mississippi"),
+    ("mo", "Missouri"),
+    ("mt", "Montana"),
+    ("ne", "Nebraska"),
+    ("nv", "Nevada"),
+    ("nh", "New Hampshire"),
+    ("nj", "New Jersey"),
+    ("nm", "New Mexico"),
+    ("ny", "New York"),
+    ("nc", "North Carolina"),
+    ("nd", "North Dakota"),
+    ("oh", "Ohio"),
+    ("ok", "Oklahoma"),
+    ("or", "Oregon"),
+    ("pa", "Pennsylvania"),
+    ("ri", "Rhode Island"),
+    ("sc", "South Carolina"),
+    ("sd", "South Dakota"),
+    ("tn", "Tennessee"),
+    ("tx", "Texas"),
+    ("ut", "Utah"),
+    ("vt", "Vermont"),
+    ("va", "Virginia"),
+    ("wa", "Washington"),
+    ("wv", "West Virginia"),
+    ("wi", "Wisconsin"),
+    ("wy", "Wyoming"),
+];
+
+/// Finds the best canonical location for a free-form name
+///
+/// The first comma-separated part of the input must match the first component
+/// of the canonical name; any further parts (region, US state abbreviation,
+/// country name or country code) narrow the match. A two-letter qualifier
+/// that is a US state abbreviation means that state, not a country, so
+/// "London, CA" is London, California. Matching ignores case and common
+/// accents.
+pub(crate) fn resolve(name: &str) -> Option<(&'static str, &'static str)> {
+    let parts: Vec<String> = name
+        .split(',')
+        .map(normalize)
+        .filter(|part| !part.is_empty())
+        .collect();
+    let (first, rest) = parts.split_first()?;
+
+    let full = parts.join(",");
+    if let Some(exact) = LOCATIONS
+        .iter()
+        .find(|(canonical, _)| normalize(canonical) == full)
+    {
+        return Some(*exact);
+    }
+
+    LOCATIONS
+        .iter()
+        .find(|(canonical, country_code)| {
+            let components: Vec<String> = canonical.split(',').map(normalize).collect();
+            components.first() == Some(first)
+                && rest.iter().all(|part| {
+                    if components.iter().skip(1).any(|c| c == part) {
+                        return true;
+                    }
+                    match us_state(part) {
+                        Some(state) => *country_code == "us" && components.contains(&state),
+                        None => part == country_code,
+                    }
+                })
+        })
+        .copied()
+}
+
+/// Gets the normalized name of a US state from its abbreviation
+fn us_state(abbreviation: &str) -> Option<String> {
+    US_STATES
+        .iter()
+        .find(|(code, _)| *code == abbreviation)
+        .map(|(_, name)| normalize(name))
+}
+
+/// Lowercases, trims and strips common Latin accents for matching
+fn normalize(value: &str) -> String {
+    value
+        .trim()
+        .chars()
+        .map(|c| match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' => {
+                'a'
+            }
+            'ç' | 'Ç' => 'c',
+            'è' | 'é' | 'ê' | 'ë' | 'È' | 'É' | 'Ê' | 'Ë' => 'e',
+            'ì' | 'í' | 'î' | 'ï' | 'Ì' | 'Í' | 'Î' | 'Ï' => 'i',
+            'ñ' | 'Ñ' => 'n',
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' => 'o',
+            'ù' | 'ú' | 'û' | 'ü' | 'Ù' | 'Ú' | 'Û' | 'Ü' => 'u',
+            _ => c,
+        })
+        .collect::<String>()
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_prefers_prominent_match() {
+        assert_eq!(
+            resolve("Paris"),
+            Some(("Paris,Paris,Ile-de-France,France", "fr"))
+        );
+        assert_eq!(
+            resolve("london"),
+            Some(("London,England,United Kingdom", "gb"))
+        );
+    }
+
+    #[test]
+    fn test_resolve_with_qualifiers() {
+        assert_eq!(
+            resolve("Paris, Texas"),
+            Some(("Paris,Texas,United States", "us"))
+        );
+        assert_eq!(
+            resolve("London, Ontario"),
+            Some(("London,Ontario,Canada", "ca"))
+        );
+        assert_eq!(
+            resolve("Zurich, CH"),
+            Some(("Zurich,Zurich,Switzerland", "ch"))
+        );
+        assert_eq!(
+            resolve("Zürich, Switzerland"),
+            Some(("Zurich,Zurich,Switzerland", "ch"))
+        );
+    }
+
+    #[test]
+    fn test_resolve_us_state_abbreviations() {
+        assert_eq!(
+            resolve("San Francisco, CA"),
+            Some(("San Francisco,California,United States", "us"))
+        );
+        assert_eq!(
+            resolve("Paris, TX"),
+            Some(("Paris,Texas,United States", "us"))
+        );
+        // CA is California here, not Canada
+        assert_eq!(resolve("London, CA"), None);
+    }
+
+    #[test]
+    fn test_resolve_exact_and_unknown() {
+        assert_eq!(
+            resolve("munich,bavaria,germany"),
+            Some(("Munich,Bavaria,Germany", "de"))
+        );
+        assert_eq!(resolve("France"), Some(("France", "fr")));
+        assert_eq!(resolve("Pariss"), None);
+        assert_eq!(resolve(" , "), None);
+    }
+}
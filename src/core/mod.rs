@@ -1,8 +1,13 @@
 /// Core module containing fundamental types and error handling
 ///
 /// This module provides the foundational components used throughout the SDK.
+pub(crate) mod codes;
 pub mod error;
+#[cfg(feature = "locations")]
+pub(crate) mod locations;
 pub mod types;
 
 pub use error::{Result, SerperError};
-pub use types::{ApiKey, BaseUrl, Location, Pagination};
+#[cfg(feature = "locations")]
+pub use types::CanonicalLocation;
+pub use types::{ApiKey, BaseUrl, CountryCode, LanguageCode, Location, Pagination};
@@ -2,7 +2,9 @@
 ///
 /// This module defines the fundamental data structures used throughout the SDK,
 /// including API keys, URLs, and common identifiers.
+use crate::core::codes;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// Represents a Serper API key
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -63,6 +65,170 @@ impl Default for BaseUrl {
     }
 }
 
+/// Represents a country code for the `gl` parameter
+///
+/// Codes are normalized to lowercase. Conversions from strings never fail so
+/// that fluent setters stay ergonomic; use [`CountryCode::new`] or
+/// [`CountryCode::validate`] to check the code against the embedded
+/// ISO 3166-1 alpha-2 table.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub struct CountryCode(String);
+
+impl CountryCode {
+    /// Creates a validated country code
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The two-letter country code (e.g., "fr", "US")
+    ///
+    /// # Returns
+    ///
+    /// Result containing the CountryCode or a validation error if the code is unknown
+    pub fn new(code: impl Into<String>) -> Result<Self, crate::core::error::SerperError> {
+        let code = Self::from(code.into());
+        code.validate()?;
+        Ok(code)
+    }
+
+    /// Returns the country code as a string reference
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the English country name, if the code is known
+    pub fn name(&self) -> Option<&'static str> {
+        codes::lookup(codes::COUNTRIES, &self.0)
+    }
+
+    /// Checks whether the code is a known country code
+    pub fn is_valid(&self) -> bool {
+        self.name().is_some()
+    }
+
+    /// Validates the code against the embedded country table
+    pub fn validate(&self) -> Result<(), crate::core::error::SerperError> {
+        if self.is_valid() {
+            return Ok(());
+        }
+
+        let message = match codes::code_for_name(codes::COUNTRIES, &self.0) {
+            Some(code) => format!(
+                "Invalid country code '{}' (did you mean '{}'?)",
+                self.0, code
+            ),
+            None => format!("Invalid country code '{}'", self.0),
+        };
+        Err(crate::core::error::SerperError::validation_error(message))
+    }
+}
+
+impl From<String> for CountryCode {
+    fn from(code: String) -> Self {
+        CountryCode(code.trim().to_lowercase())
+    }
+}
+
+impl From<&str> for CountryCode {
+    fn from(code: &str) -> Self {
+        Self::from(code.to_string())
+    }
+}
+
+impl From<CountryCode> for String {
+    fn from(code: CountryCode) -> Self {
+        code.0
+    }
+}
+
+impl fmt::Display for CountryCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Represents a language code for the `hl` parameter
+///
+/// Codes are normalized to lowercase. Conversions from strings never fail so
+/// that fluent setters stay ergonomic; use [`LanguageCode::new`] or
+/// [`LanguageCode::validate`] to check the code against the embedded table of
+/// Google-supported languages.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub struct LanguageCode(String);
+
+impl LanguageCode {
+    /// Creates a validated language code
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The language code (e.g., "en", "pt-BR")
+    ///
+    /// # Returns
+    ///
+    /// Result containing the LanguageCode or a validation error if the code is unknown
+    pub fn new(code: impl Into<String>) -> Result<Self, crate::core::error::SerperError> {
+        let code = Self::from(code.into());
+        code.validate()?;
+        Ok(code)
+    }
+
+    /// Returns the language code as a string reference
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the English language name, if the code is known
+    pub fn name(&self) -> Option<&'static str> {
+        codes::lookup(codes::LANGUAGES, &self.0)
+    }
+
+    /// Checks whether the code is a known language code
+    pub fn is_valid(&self) -> bool {
+        self.name().is_some()
+    }
+
+    /// Validates the code against the embedded language table
+    pub fn validate(&self) -> Result<(), crate::core::error::SerperError> {
+        if self.is_valid() {
+            return Ok(());
+        }
+
+        let message = match codes::code_for_name(codes::LANGUAGES, &self.0) {
+            Some(code) => format!(
+                "Invalid language code '{}' (did you mean '{}'?)",
+                self.0, code
+            ),
+            None => format!("Invalid language code '{}'", self.0),
+        };
+        Err(crate::core::error::SerperError::validation_error(message))
+    }
+}
+
+impl From<String> for LanguageCode {
+    fn from(code: String) -> Self {
+        LanguageCode(code.trim().to_lowercase())
+    }
+}
+
+impl From<&str> for LanguageCode {
+    fn from(code: &str) -> Self {
+        Self::from(code.to_string())
+    }
+}
+
+impl From<LanguageCode> for String {
+    fn from(code: LanguageCode) -> Self {
+        code.0
+    }
+}
+
+impl fmt::Display for LanguageCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
 /// Represents pagination parameters
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Pagination {
@@ -106,9 +272,9 @@ pub struct Location {
     /// Human-readable location (e.g., "Paris, France")
     pub location: Option<String>,
     /// Country code (e.g., "fr")
-    pub country_code: Option<String>,
+    pub country_code: Option<CountryCode>,
     /// Language code (e.g., "en")
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
 }
 
 impl Location {
@@ -128,24 +294,76 @@ impl Location {
     }
 
     /// Sets the country code
-    pub fn with_country(mut self, country: String) -> Self {
-        self.country_code = Some(country);
+    pub fn with_country(mut self, country: impl Into<CountryCode>) -> Self {
+        self.country_code = Some(country.into());
         self
     }
 
     /// Sets the language code
-    pub fn with_language(mut self, language: String) -> Self {
-        self.language_code = Some(language);
+    pub fn with_language(mut self, language: impl Into<LanguageCode>) -> Self {
+        self.language_code = Some(language.into());
         self
     }
 }
 
+#[cfg(feature = "locations")]
+impl Location {
+    /// Resolves a free-form place name to a Google canonical location
+    ///
+    /// Uses the embedded offline location table. Qualifiers after a comma
+    /// (region, country name or country code) disambiguate places that share
+    /// a name, e.g. "Paris, Texas" or "London, Ontario". Two-letter US state
+    /// abbreviations are understood, so "San Francisco, CA" is California.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The place name (e.g., "Paris", "Munich, Germany")
+    ///
+    /// # Returns
+    ///
+    /// The best canonical match, or None if the name is not in the table
+    pub fn resolve(name: &str) -> Option<CanonicalLocation> {
+        crate::core::locations::resolve(name).map(|(canonical_name, country_code)| {
+            CanonicalLocation {
+                canonical_name,
+                country_code: CountryCode::from(country_code),
+            }
+        })
+    }
+
+    /// Checks whether a location string is a known canonical location name
+    pub fn is_canonical(location: &str) -> bool {
+        crate::core::locations::LOCATIONS
+            .iter()
+            .any(|(canonical_name, _)| *canonical_name == location)
+    }
+}
+
 impl Default for Location {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// A Google canonical location resolved from the embedded location table
+#[cfg(feature = "locations")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CanonicalLocation {
+    /// Canonical location name (e.g., "Paris,Paris,Ile-de-France,France")
+    pub canonical_name: &'static str,
+    /// Country code of the location
+    pub country_code: CountryCode,
+}
+
+#[cfg(feature = "locations")]
+impl From<CanonicalLocation> for Location {
+    fn from(resolved: CanonicalLocation) -> Self {
+        Location::new()
+            .with_location(resolved.canonical_name.to_string())
+            .with_country(resolved.country_code)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -184,7 +402,54 @@ mod tests {
             .with_language("en".to_string());
 
         assert_eq!(location.location, Some("Paris".to_string()));
-        assert_eq!(location.country_code, Some("fr".to_string()));
-        assert_eq!(location.language_code, Some("en".to_string()));
+        assert_eq!(location.country_code, Some("fr".into()));
+        assert_eq!(location.language_code, Some("en".into()));
+    }
+
+    #[cfg(feature = "locations")]
+    #[test]
+    fn test_location_resolve() {
+        let resolved = Location::resolve("Paris").unwrap();
+        assert_eq!(resolved.canonical_name, "Paris,Paris,Ile-de-France,France");
+        assert_eq!(resolved.country_code.as_str(), "fr");
+
+        let location = Location::from(resolved);
+        assert_eq!(
+            location.location.as_deref(),
+            Some("Paris,Paris,Ile-de-France,France")
+        );
+        assert_eq!(location.country_code, Some("fr".into()));
+
+        assert!(Location::is_canonical("Berlin,Berlin,Germany"));
+        assert!(!Location::is_canonical("Berlin"));
+        assert!(Location::resolve("Pariss").is_none());
+    }
+
+    #[test]
+    fn test_country_code() {
+        let code = CountryCode::new("FR").unwrap();
+        assert_eq!(code.as_str(), "fr");
+        assert_eq!(code.name(), Some("France"));
+
+        let error = CountryCode::new("france").unwrap_err();
+        assert!(error.to_string().contains("did you mean 'fr'"));
+        assert!(!CountryCode::from("zz").is_valid());
+    }
+
+    #[test]
+    fn test_language_code() {
+        let code = LanguageCode::new("pt-BR").unwrap();
+        assert_eq!(code.as_str(), "pt-br");
+        assert!(LanguageCode::from("en").is_valid());
+
+        let error = LanguageCode::new("english").unwrap_err();
+        assert!(error.to_string().contains("did you mean 'en'"));
+    }
+
+    #[test]
+    fn test_code_serde() {
+        let code: CountryCode = serde_json::from_str("\"DE\"").unwrap();
+        assert_eq!(code.as_str(), "de");
+        assert_eq!(serde_json::to_string(&code).unwrap(), "\"de\"");
     }
 }
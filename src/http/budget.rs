@@ -0,0 +1,353 @@
+use crate::core::{Result, SerperError};
+/// Credit budget module
+///
+/// This module caps the credits a client may spend, so a runaway loop
+/// fails fast instead of draining the account.
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Cap on the credits spent by the clients sharing it
+///
+/// The client reserves what each query costs before sending a request (one
+/// credit, or two when `num` is above 10), and settles the reservation with
+/// the `credits` field of the response once it arrives; a response without
+/// the field costs what was reserved. Reserved credits count as spent, so
+/// concurrent requests cannot go past the limit together, and a batch chunk
+/// costing more than the credits left is shrunk to fit. Once a query no
+/// longer fits, requests fail with `SerperError::BudgetExceeded` without
+/// being sent. Cached responses are free and still served.
+///
+/// Only a request that never reached the server, e.g. one rejected by the
+/// circuit breaker or unable to connect, gives its credits back; any other
+/// failure, including an unreadable response, keeps them spent. A hedged
+/// duplicate reserves its own credits, which stay spent if the duplicate is
+/// abandoned. Spending can only pass the limit when a response reports more
+/// credits than reserved.
+///
+/// A budget covers whatever shares it: one `Arc` for the whole process, or
+/// a new budget per job. Windowed budgets start over every `window`.
+///
+/// # Example
+///
+/// ```rust
+/// use serper_sdk::http::CreditBudget;
+/// use serper_sdk::search::service::SearchServiceBuilder;
+/// use std::sync::Arc;
+/// use std::time::Duration;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     // At most 5000 credits a day across every service sharing the budget
+///     let daily = Arc::new(CreditBudget::per_window(5000, Duration::from_secs(24 * 60 * 60))?);
+///
+///     let service = SearchServiceBuilder::new()
+///         .api_key("demo-key-for-docs")
+///         .credit_budget(Arc::clone(&daily))
+///         .build()?;
+///
+///     assert_eq!(service.credit_budget().unwrap().remaining(), 5000);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct CreditBudget {
+    limit: u64,
+    window: Option<Duration>,
+    state: Mutex<BudgetState>,
+}
+
+/// Mutable state of a credit budget
+#[derive(Debug)]
+struct BudgetState {
+    /// Credits charged or reserved in the current window
+    spent: u64,
+    window_start: Instant,
+    /// Incremented whenever the spent credits start over
+    generation: u64,
+}
+
+/// Credits held for a request until its response is charged
+#[derive(Debug)]
+pub(crate) struct Reservation {
+    credits: u64,
+    /// Budget generation the credits were reserved in
+    generation: u64,
+}
+
+impl Reservation {
+    /// Gets the number of reserved credits
+    pub(crate) fn credits(&self) -> u64 {
+        self.credits
+    }
+}
+
+impl CreditBudget {
+    /// Creates a budget that never resets
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - Maximum number of credits to spend
+    ///
+    /// # Returns
+    ///
+    /// Result containing the budget or a configuration error
+    pub fn new(limit: u64) -> Result<Self> {
+        Self::build(limit, None)
+    }
+
+    /// Creates a budget that starts over every `window`
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - Maximum number of credits to spend per window
+    /// * `window` - Length of each window, starting when the budget is created
+    ///
+    /// # Returns
+    ///
+    /// Result containing the budget or a configuration error
+    pub fn per_window(limit: u64, window: Duration) -> Result<Self> {
+        if window.is_zero() {
+            return Err(SerperError::config_error(
+                "Credit budget window must be longer than zero",
+            ));
+        }
+        Self::build(limit, Some(window))
+    }
+
+    /// Validates the limit and creates the budget
+    fn build(limit: u64, window: Option<Duration>) -> Result<Self> {
+        if limit == 0 {
+            return Err(SerperError::config_error(
+                "Credit budget must allow at least one credit",
+            ));
+        }
+
+        Ok(Self {
+            limit,
+            window,
+            state: Mutex::new(BudgetState {
+                spent: 0,
+                window_start: Instant::now(),
+                generation: 0,
+            }),
+        })
+    }
+
+    /// Gets the maximum number of credits per window
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Gets the window length, or `None` if the budget never resets
+    pub fn window(&self) -> Option<Duration> {
+        self.window
+    }
+
+    /// Gets the credits spent in the current window, including reserved ones
+    pub fn spent(&self) -> u64 {
+        self.lock().spent
+    }
+
+    /// Gets the credits left in the current window
+    pub fn remaining(&self) -> u64 {
+        self.limit.saturating_sub(self.spent())
+    }
+
+    /// Checks if the budget is used up
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// Forgets the credits spent so far, e.g. when starting a new job
+    pub fn reset(&self) {
+        let mut state = self.lock();
+        state.spent = 0;
+        state.window_start = Instant::now();
+        state.generation += 1;
+    }
+
+    /// Reserves credits for a request about to be sent
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - Credits the request is expected to cost
+    ///
+    /// # Returns
+    ///
+    /// A reservation of `max` credits, or of all credits left if fewer
+    /// remain, or `SerperError::BudgetExceeded` if none are left
+    pub(crate) fn reserve(&self, max: u64) -> Result<Reservation> {
+        let mut state = self.lock();
+        let credits = max.min(self.limit.saturating_sub(state.spent));
+        if credits == 0 {
+            return Err(self.exceeded(&state));
+        }
+
+        state.spent += credits;
+        Ok(Reservation {
+            credits,
+            generation: state.generation,
+        })
+    }
+
+    /// Reserves exactly the credits a request costs
+    ///
+    /// # Returns
+    ///
+    /// A reservation of `credits`, or `SerperError::BudgetExceeded` if fewer
+    /// are left
+    pub(crate) fn reserve_exact(&self, credits: u64) -> Result<Reservation> {
+        let mut state = self.lock();
+        if credits > self.limit.saturating_sub(state.spent) {
+            return Err(self.exceeded(&state));
+        }
+
+        state.spent += credits;
+        Ok(Reservation {
+            credits,
+            generation: state.generation,
+        })
+    }
+
+    /// Gives back the part of a reservation above `credits`
+    pub(crate) fn shrink(&self, reservation: &mut Reservation, credits: u64) {
+        let released = reservation.credits.saturating_sub(credits);
+        let mut state = self.lock();
+        if state.generation == reservation.generation {
+            state.spent = state.spent.saturating_sub(released);
+        }
+        reservation.credits -= released;
+    }
+
+    /// Replaces a reservation by the credits actually charged
+    ///
+    /// Reservations from an earlier window were already forgotten, so only
+    /// the charge is recorded for them.
+    pub(crate) fn settle(&self, reservation: Reservation, charged: u64) {
+        let mut state = self.lock();
+        if state.generation == reservation.generation {
+            state.spent = state.spent.saturating_sub(reservation.credits);
+        }
+        state.spent = state.spent.saturating_add(charged);
+    }
+
+    /// Gets the error for a request that does not fit in the budget
+    fn exceeded(&self, state: &BudgetState) -> SerperError {
+        SerperError::BudgetExceeded {
+            limit: self.limit,
+            resets_in: self
+                .window
+                .map(|window| window.saturating_sub(state.window_start.elapsed())),
+        }
+    }
+
+    /// Locks the budget state, starting a new window if the current one ended
+    fn lock(&self) -> std::sync::MutexGuard<'_, BudgetState> {
+        let mut state = self
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        if let Some(window) = self.window {
+            let elapsed = state.window_start.elapsed();
+            if elapsed >= window {
+                // Keep windows aligned to the creation time
+                let windows = (elapsed.as_nanos() / window.as_nanos()) as u32;
+                state.window_start += window * windows;
+                state.spent = 0;
+                state.generation += 1;
+            }
+        }
+        state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_budgets_are_rejected() {
+        assert!(CreditBudget::new(0).is_err());
+        assert!(CreditBudget::per_window(10, Duration::ZERO).is_err());
+    }
+
+    #[test]
+    fn test_budget_rejects_once_spent() {
+        let budget = CreditBudget::new(3).unwrap();
+
+        let reservation = budget.reserve(1).unwrap();
+        budget.settle(reservation, 2);
+        assert_eq!(budget.remaining(), 1);
+
+        let reservation = budget.reserve(1).unwrap();
+        budget.settle(reservation, 2);
+        assert_eq!((budget.spent(), budget.remaining()), (4, 0));
+        let error = budget.reserve(1).unwrap_err();
+        assert!(matches!(
+            error,
+            SerperError::BudgetExceeded {
+                limit: 3,
+                resets_in: None
+            }
+        ));
+
+        budget.reset();
+        assert!(budget.reserve(1).is_ok());
+    }
+
+    #[test]
+    fn test_reservations_hold_credits_until_settled() {
+        let budget = CreditBudget::new(5).unwrap();
+
+        // A chunk of 4 fits; the next one is shrunk to the single credit left
+        let chunk = budget.reserve(4).unwrap();
+        assert_eq!(chunk.credits(), 4);
+        let last = budget.reserve(4).unwrap();
+        assert_eq!(last.credits(), 1);
+        assert!(budget.reserve(1).unwrap_err().is_budget_exceeded());
+
+        // A request that never reached the server gives its credits back
+        budget.settle(chunk, 0);
+        assert_eq!(budget.remaining(), 4);
+        budget.settle(last, 1);
+        assert_eq!(budget.spent(), 1);
+    }
+
+    #[test]
+    fn test_exact_reservations_and_shrinking() {
+        let budget = CreditBudget::new(3).unwrap();
+
+        let mut reservation = budget.reserve(3).unwrap();
+        budget.shrink(&mut reservation, 1);
+        assert_eq!((reservation.credits(), budget.remaining()), (1, 2));
+
+        // A two-credit query fits, then only one credit is left
+        let two = budget.reserve_exact(2).unwrap();
+        assert!(budget.reserve_exact(1).unwrap_err().is_budget_exceeded());
+        budget.settle(two, 0);
+        assert!(budget.reserve_exact(2).is_ok());
+        assert!(budget.reserve_exact(1).unwrap_err().is_budget_exceeded());
+    }
+
+    #[test]
+    fn test_window_starts_over() {
+        let budget = CreditBudget::per_window(1, Duration::from_millis(30)).unwrap();
+        let stale = budget.reserve(1).unwrap();
+
+        let Err(SerperError::BudgetExceeded {
+            resets_in: Some(resets_in),
+            ..
+        }) = budget.reserve(1)
+        else {
+            panic!("expected a windowed budget error");
+        };
+        assert!(resets_in <= Duration::from_millis(30));
+
+        std::thread::sleep(Duration::from_millis(40));
+        assert_eq!(budget.spent(), 0);
+
+        // Settling a reservation from the previous window only adds the charge
+        budget.settle(stale, 1);
+        assert_eq!(budget.spent(), 1);
+    }
+}
@@ -0,0 +1,253 @@
+use crate::core::{Result, SerperError};
+/// Circuit breaker module
+///
+/// This module provides a circuit breaker that stops sending requests after
+/// repeated transient failures, so callers fail fast during an API incident.
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Circuit breaker configuration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive transient failures that open the circuit
+    pub failure_threshold: u32,
+    /// How long the circuit stays open before a probe request is allowed
+    pub open_duration: Duration,
+}
+
+impl CircuitBreakerConfig {
+    /// Creates a configuration that opens after 5 failures for 30 seconds
+    pub fn new() -> Self {
+        Self {
+            failure_threshold: 5,
+            open_duration: Duration::from_secs(30),
+        }
+    }
+
+    /// Sets the number of consecutive transient failures that open the circuit
+    pub fn with_failure_threshold(mut self, threshold: u32) -> Self {
+        self.failure_threshold = threshold.max(1);
+        self
+    }
+
+    /// Sets how long the circuit stays open before probing
+    pub fn with_open_duration(mut self, duration: Duration) -> Self {
+        self.open_duration = duration;
+        self
+    }
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// State of a circuit breaker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests flow normally
+    Closed,
+    /// Requests fail fast with [`SerperError::CircuitOpen`]
+    Open,
+    /// One probe request is allowed to test whether the API has recovered
+    HalfOpen,
+}
+
+/// Circuit breaker tracking consecutive transient failures
+///
+/// Only transient failures (see [`SerperError::is_retryable`]) count; any
+/// other outcome shows the API is reachable and closes the circuit.
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    inner: Mutex<BreakerInner>,
+}
+
+/// Mutable state of a circuit breaker
+#[derive(Debug)]
+struct BreakerInner {
+    state: CircuitState,
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    probe_started: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    /// Creates a closed circuit breaker
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            inner: Mutex::new(BreakerInner {
+                state: CircuitState::Closed,
+                consecutive_failures: 0,
+                opened_at: None,
+                probe_started: None,
+            }),
+        }
+    }
+
+    /// Gets the breaker configuration
+    pub fn config(&self) -> &CircuitBreakerConfig {
+        &self.config
+    }
+
+    /// Gets the current state
+    ///
+    /// An open circuit whose open duration has elapsed reports `HalfOpen`.
+    pub fn state(&self) -> CircuitState {
+        let inner = self.lock();
+        match inner.state {
+            CircuitState::Open if self.cooldown_elapsed(&inner) => CircuitState::HalfOpen,
+            state => state,
+        }
+    }
+
+    /// Checks whether a request may be sent
+    ///
+    /// # Returns
+    ///
+    /// `Ok` if the request may be sent, or `SerperError::CircuitOpen` if the
+    /// circuit is open or a half-open probe is already in flight
+    pub fn check(&self) -> Result<()> {
+        let mut inner = self.lock();
+        let now = Instant::now();
+
+        match inner.state {
+            CircuitState::Closed => Ok(()),
+            CircuitState::Open if self.cooldown_elapsed(&inner) => {
+                inner.state = CircuitState::HalfOpen;
+                inner.probe_started = Some(now);
+                Ok(())
+            }
+            CircuitState::Open => Err(SerperError::CircuitOpen),
+            CircuitState::HalfOpen => {
+                // Allow a new probe if the previous one was abandoned
+                let probe_stale = inner
+                    .probe_started
+                    .is_none_or(|started| now.duration_since(started) >= self.config.open_duration);
+                if probe_stale {
+                    inner.probe_started = Some(now);
+                    Ok(())
+                } else {
+                    Err(SerperError::CircuitOpen)
+                }
+            }
+        }
+    }
+
+    /// Records the outcome of a request sent after [`CircuitBreaker::check`]
+    pub fn record(&self, result: std::result::Result<(), &SerperError>) {
+        match result {
+            Err(error) if error.is_retryable() => self.record_failure(),
+            _ => self.record_success(),
+        }
+    }
+
+    /// Records a successful request, closing the circuit
+    pub fn record_success(&self) {
+        let mut inner = self.lock();
+        inner.state = CircuitState::Closed;
+        inner.consecutive_failures = 0;
+        inner.opened_at = None;
+        inner.probe_started = None;
+    }
+
+    /// Records a transient failure, opening the circuit at the threshold
+    pub fn record_failure(&self) {
+        let mut inner = self.lock();
+        inner.consecutive_failures = inner.consecutive_failures.saturating_add(1);
+
+        if inner.state == CircuitState::HalfOpen
+            || inner.consecutive_failures >= self.config.failure_threshold
+        {
+            inner.state = CircuitState::Open;
+            inner.opened_at = Some(Instant::now());
+            inner.probe_started = None;
+        }
+    }
+
+    /// Checks if an open circuit may be probed again
+    fn cooldown_elapsed(&self, inner: &BreakerInner) -> bool {
+        inner
+            .opened_at
+            .is_some_and(|opened| opened.elapsed() >= self.config.open_duration)
+    }
+
+    /// Locks the breaker state, recovering from a poisoned lock
+    fn lock(&self) -> std::sync::MutexGuard<'_, BreakerInner> {
+        self.inner
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breaker(open_duration: Duration) -> CircuitBreaker {
+        CircuitBreaker::new(
+            CircuitBreakerConfig::new()
+                .with_failure_threshold(2)
+                .with_open_duration(open_duration),
+        )
+    }
+
+    #[test]
+    fn test_opens_after_consecutive_failures() {
+        let breaker = breaker(Duration::from_secs(60));
+        let unavailable = SerperError::Http {
+            status: 503,
+            body: None,
+            request_id: None,
+        };
+
+        breaker.record(Err(&unavailable));
+        breaker.record(Ok(()));
+        breaker.record(Err(&unavailable));
+        assert_eq!(breaker.state(), CircuitState::Closed);
+
+        breaker.record(Err(&unavailable));
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(matches!(breaker.check(), Err(SerperError::CircuitOpen)));
+    }
+
+    #[test]
+    fn test_permanent_errors_do_not_open() {
+        let breaker = breaker(Duration::from_secs(60));
+        for _ in 0..5 {
+            breaker.record(Err(&SerperError::InvalidApiKey));
+        }
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_half_open_probe() {
+        let breaker = breaker(Duration::ZERO);
+        breaker.record_failure();
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+
+        // The probe is let through; a failed probe re-opens the circuit
+        assert!(breaker.check().is_ok());
+        breaker.record_failure();
+
+        // A successful probe closes it
+        assert!(breaker.check().is_ok());
+        breaker.record_success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_single_probe_in_flight() {
+        let breaker = breaker(Duration::from_millis(20));
+        breaker.record_failure();
+        breaker.record_failure();
+        assert!(breaker.check().is_err());
+
+        std::thread::sleep(Duration::from_millis(25));
+        assert!(breaker.check().is_ok());
+        assert!(matches!(breaker.check(), Err(SerperError::CircuitOpen)));
+    }
+}
@@ -1,30 +1,95 @@
+#[cfg(feature = "disk-cache")]
+use crate::search::disk_cache::DiskCache;
 /// High-level HTTP client functionality
 ///
 /// This module provides a high-level HTTP client that combines transport
 /// layer functionality with Serper API-specific operations.
 use crate::{
     core::{
-        Result,
+        Result, SerperError,
         types::{ApiKey, BaseUrl},
     },
-    http::transport::{HttpTransport, TransportConfig},
+    http::{
+        budget::{CreditBudget, Reservation},
+        circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, CircuitState},
+        hedge::{HedgeConfig, HedgeStats, Hedger},
+        key_pool::ApiKeyPool,
+        middleware::{AfterResponse, BeforeRequest, Middleware, MiddlewareTransport},
+        options::RequestOptions,
+        rate_limit::RateLimiter,
+        single_flight::SingleFlight,
+        transport::{
+            API_KEY_HEADER, ConfiguredTransport, HttpTransport, Request, Response, Transport,
+            TransportConfig,
+        },
+    },
     search::{
+        cache::{CacheConfig, ResponseCache},
         query::SearchQuery,
+        report::{BatchItem, BatchReport},
         response::{ResponseParser, SearchResponse},
     },
+    utils::retry::RetryConfig,
 };
+use futures::stream::{self, Stream, StreamExt};
+use serde::Serialize;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Maximum number of queries Serper accepts in one batch request
+pub const MAX_BATCH_SIZE: usize = 100;
 
 /// High-level HTTP client for Serper API operations
 ///
 /// This client handles authentication, request formatting, response parsing,
 /// and error handling for all Serper API interactions.
-#[derive(Debug)]
+///
+/// Cloning is cheap: clones share the same connection pool, so one client
+/// can be handed to many concurrent tasks.
+#[derive(Debug, Clone)]
 pub struct SerperHttpClient {
-    transport: HttpTransport,
+    inner: Arc<ClientInner>,
+}
+
+/// Shared state behind a [`SerperHttpClient`]
+struct ClientInner {
+    transport: Box<dyn Transport>,
+    config: TransportConfig,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    cache: Option<ResponseCache>,
+    #[cfg(feature = "disk-cache")]
+    disk_cache: Option<Arc<DiskCache>>,
+    /// Searches in flight, shared by concurrent callers; `None` if disabled
+    in_flight: Option<SingleFlight<SearchResponse>>,
+    hedger: Option<Hedger>,
+    key_pool: Option<ApiKeyPool>,
+    credit_budget: Option<Arc<CreditBudget>>,
     api_key: ApiKey,
     base_url: BaseUrl,
 }
 
+impl fmt::Debug for ClientInner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("ClientInner");
+        debug
+            .field("transport", &"<dyn Transport>")
+            .field("config", &self.config)
+            .field("circuit_breaker", &self.circuit_breaker)
+            .field("cache", &self.cache);
+        #[cfg(feature = "disk-cache")]
+        debug.field("disk_cache", &self.disk_cache);
+        debug
+            .field("coalesce_requests", &self.in_flight.is_some())
+            .field("hedger", &self.hedger)
+            .field("key_pool", &self.key_pool)
+            .field("credit_budget", &self.credit_budget)
+            .field("api_key", &self.api_key)
+            .field("base_url", &self.base_url)
+            .finish()
+    }
+}
+
 impl SerperHttpClient {
     /// Creates a new HTTP client with the specified API key
     ///
@@ -36,14 +101,7 @@ impl SerperHttpClient {
     ///
     /// Result containing the HTTP client or an error
     pub fn new(api_key: ApiKey) -> Result<Self> {
-        let transport = HttpTransport::new()?;
-        let base_url = BaseUrl::default();
-
-        Ok(Self {
-            transport,
-            api_key,
-            base_url,
-        })
+        Self::with_config(api_key, BaseUrl::default(), TransportConfig::new())
     }
 
     /// Creates a new HTTP client with custom configuration
@@ -62,13 +120,40 @@ impl SerperHttpClient {
         base_url: BaseUrl,
         config: TransportConfig,
     ) -> Result<Self> {
-        let transport = HttpTransport::with_config(config)?;
+        SerperHttpClientBuilder::new()
+            .api_key(api_key)
+            .base_url(base_url)
+            .transport_config(config)
+            .build()
+    }
 
-        Ok(Self {
-            transport,
-            api_key,
-            base_url,
-        })
+    /// Creates a new HTTP client that sends requests through a custom transport
+    ///
+    /// The default [`TransportConfig`] applies, so requests time out after 30
+    /// seconds and are not retried. Use [`SerperHttpClientBuilder::transport`]
+    /// to combine a custom transport with retries, rate limiting, caching or
+    /// other client options.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - The Serper API key
+    /// * `base_url` - Base URL for the API
+    /// * `transport` - The transport that sends requests
+    ///
+    /// # Returns
+    ///
+    /// The HTTP client
+    pub fn with_transport(
+        api_key: ApiKey,
+        base_url: BaseUrl,
+        transport: impl Transport + 'static,
+    ) -> Self {
+        SerperHttpClientBuilder::new()
+            .api_key(api_key)
+            .base_url(base_url)
+            .transport(transport)
+            .build()
+            .expect("a builder with an API key and a custom transport cannot fail")
     }
 
     /// Executes a search query
@@ -81,21 +166,186 @@ impl SerperHttpClient {
     ///
     /// Result containing the search response or an error
     pub async fn search(&self, query: &SearchQuery) -> Result<SearchResponse> {
+        self.search_cached(query, None, None).await
+    }
+
+    /// Executes a search query, accepting cached responses up to `max_age` old
+    ///
+    /// `max_age` overrides the cache TTLs for this call; `Duration::ZERO`
+    /// always sends the request and refreshes the caches.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The search query to execute
+    /// * `max_age` - Maximum age of an acceptable cached response
+    ///
+    /// # Returns
+    ///
+    /// Result containing the search response or an error
+    pub async fn search_with_max_age(
+        &self,
+        query: &SearchQuery,
+        max_age: Duration,
+    ) -> Result<SearchResponse> {
+        self.search_cached(query, Some(max_age), None).await
+    }
+
+    /// Executes a search query with per-call options
+    ///
+    /// `options.timeout` is a deadline for the whole call, including retries,
+    /// backoff and rate limiter waits, after which it fails with
+    /// `SerperError::Timeout`. It also overrides [`TransportConfig::timeout`]
+    /// for each request attempt; custom transports receive it as
+    /// [`Request::timeout`]. Calls with a timeout are not coalesced with
+    /// other in-flight searches. When `options.cancel` fires, the call stops
+    /// waiting, drops the request and fails with `SerperError::Cancelled`.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The search query to execute
+    /// * `options` - Timeout and cancellation token for this call
+    ///
+    /// # Returns
+    ///
+    /// Result containing the search response or an error
+    pub async fn search_with_options(
+        &self,
+        query: &SearchQuery,
+        options: RequestOptions,
+    ) -> Result<SearchResponse> {
+        options
+            .run(self.search_cached(query, None, options.timeout))
+            .await
+    }
+
+    /// Executes a search query through the caches
+    async fn search_cached(
+        &self,
+        query: &SearchQuery,
+        max_age: Option<Duration>,
+        timeout: Option<Duration>,
+    ) -> Result<SearchResponse> {
         // Validate query before sending
         query.validate()?;
 
-        let url = format!("{}/search", self.base_url.as_str());
+        if let Some(cached) = self.cached(query, max_age).await {
+            return Ok(cached);
+        }
 
-        let response = self.transport.post_json(&url, &self.api_key, query).await?;
+        match (&self.inner.in_flight, timeout) {
+            (Some(in_flight), None) => {
+                // Only identical queries are coalesced, unlike cache lookups
+                let key = serde_json::to_string(query)?;
+                in_flight.run(key, || self.fetch(query, None)).await
+            }
+            _ => self.fetch(query, timeout).await,
+        }
+    }
 
-        let search_response = self.transport.parse_json(response).await?;
+    /// Sends a search request and stores the response in the caches
+    async fn fetch(
+        &self,
+        query: &SearchQuery,
+        timeout: Option<Duration>,
+    ) -> Result<SearchResponse> {
+        let url = format!("{}/search", self.inner.base_url.as_str());
+
+        let search_response = match &self.inner.hedger {
+            Some(hedger) => {
+                hedger
+                    .run(|| self.send_search(&url, query, timeout))
+                    .await?
+            }
+            None => self.send_search(&url, query, timeout).await?,
+        };
 
         // Validate response structure
         ResponseParser::validate_response(&search_response)?;
 
+        self.store(query, &search_response).await;
+
         Ok(search_response)
     }
 
+    /// Sends one search request, reserving its credits of the budget first
+    ///
+    /// Each hedged copy calls this, so a duplicate is charged when it is sent
+    /// and keeps its credits if it is abandoned.
+    async fn send_search(
+        &self,
+        url: &str,
+        query: &SearchQuery,
+        timeout: Option<Duration>,
+    ) -> Result<SearchResponse> {
+        let cost = Self::query_credits(query);
+        let reservation = self.reserve(cost)?;
+        let result = match self.post_json(url, query, timeout).await {
+            Ok(response) => response.json::<SearchResponse>(),
+            Err(e) => {
+                self.settle_failure(reservation, &e);
+                return Err(e);
+            }
+        };
+
+        // Serper charged for the answer even if it cannot be read
+        let charged = result
+            .as_ref()
+            .map_or(cost, |response| response.credits.map_or(cost, u64::from));
+        self.settle(reservation, charged);
+        result
+    }
+
+    /// Looks up a cached response, in memory first and then on disk
+    ///
+    /// Disk lookups run on tokio's blocking thread pool.
+    async fn cached(
+        &self,
+        query: &SearchQuery,
+        max_age: Option<Duration>,
+    ) -> Option<SearchResponse> {
+        if let Some(cache) = &self.inner.cache {
+            let hit = match max_age {
+                Some(max_age) => cache.get_with_max_age(query, max_age),
+                None => cache.get(query),
+            };
+            if hit.is_some() {
+                return hit;
+            }
+        }
+
+        #[cfg(feature = "disk-cache")]
+        if let Some(disk_cache) = &self.inner.disk_cache {
+            let disk_cache = Arc::clone(disk_cache);
+            let query = query.clone();
+            return tokio::task::spawn_blocking(move || match max_age {
+                Some(max_age) => disk_cache.get_with_max_age(&query, max_age),
+                None => disk_cache.get(&query),
+            })
+            .await
+            .ok()
+            .flatten();
+        }
+
+        None
+    }
+
+    /// Stores a response in the enabled caches
+    ///
+    /// Disk writes run on tokio's blocking thread pool; their failures are
+    /// ignored, since the search itself succeeded.
+    async fn store(&self, query: &SearchQuery, response: &SearchResponse) {
+        if let Some(cache) = &self.inner.cache {
+            cache.insert(query, response.clone());
+        }
+
+        #[cfg(feature = "disk-cache")]
+        if let Some(disk_cache) = &self.inner.disk_cache {
+            let disk_cache = Arc::clone(disk_cache);
+            let (query, response) = (query.clone(), response.clone());
+            let _ = tokio::task::spawn_blocking(move || disk_cache.insert(&query, &response)).await;
+        }
+    }
+
     /// Executes multiple search queries in sequence
     ///
     /// # Arguments
@@ -116,12 +366,137 @@ impl SerperHttpClient {
         Ok(results)
     }
 
+    /// Executes search queries using Serper's native batch endpoint
+    ///
+    /// Queries are split into chunks of at most `batch_size` (capped at
+    /// [`MAX_BATCH_SIZE`]) and each chunk is sent as a single POST with a JSON
+    /// array body. Invalid queries are reported without being sent, cached
+    /// queries are answered from the cache, and a failed chunk request is
+    /// reported for every query in that chunk. With a credit budget, a chunk
+    /// is cut to the credits left and the queries beyond the budget fail with
+    /// `SerperError::BudgetExceeded`.
+    ///
+    /// # Arguments
+    ///
+    /// * `queries` - The search queries to execute
+    /// * `batch_size` - Maximum number of queries per request
+    ///
+    /// # Returns
+    ///
+    /// One result per query, in the same order as `queries`
+    pub async fn search_batch(
+        &self,
+        queries: &[SearchQuery],
+        batch_size: usize,
+    ) -> Vec<Result<SearchResponse>> {
+        self.search_batch_with_options(queries, batch_size, RequestOptions::new())
+            .await
+    }
+
+    /// Executes search queries using the batch endpoint with per-call options
+    ///
+    /// Works like [`SerperHttpClient::search_batch`], with `options.timeout`
+    /// as the deadline of each chunk request, retries included. When `options.cancel` fires, the chunk
+    /// in flight is dropped and it and every chunk not yet sent fail with
+    /// `SerperError::Cancelled`; results of completed chunks are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `queries` - The search queries to execute
+    /// * `batch_size` - Maximum number of queries per request
+    /// * `options` - Timeout and cancellation token for this call
+    ///
+    /// # Returns
+    ///
+    /// One result per query, in the same order as `queries`
+    pub async fn search_batch_with_options(
+        &self,
+        queries: &[SearchQuery],
+        batch_size: usize,
+        options: RequestOptions,
+    ) -> Vec<Result<SearchResponse>> {
+        let batch_size = batch_size.clamp(1, MAX_BATCH_SIZE);
+        let mut results: Vec<Option<Result<SearchResponse>>> =
+            queries.iter().map(|_| None).collect();
+
+        let mut valid = Vec::with_capacity(queries.len());
+        for (idx, query) in queries.iter().enumerate() {
+            if let Err(e) = query.validate() {
+                results[idx] = Some(Err(e));
+            } else if let Some(cached) = self.cached(query, None).await {
+                results[idx] = Some(Ok(cached));
+            } else {
+                valid.push(idx);
+            }
+        }
+
+        let url = format!("{}/search", self.inner.base_url.as_str());
+
+        let mut pending = valid.as_slice();
+        while !pending.is_empty() {
+            // Shrink the chunk to the queries the budget can pay for
+            let candidates = &pending[..pending.len().min(batch_size)];
+            let (size, reservation) = match self.reserve_chunk(queries, candidates) {
+                Ok(reserved) => reserved,
+                Err(e) => {
+                    for &idx in pending {
+                        results[idx] = Some(Err(Self::batch_error(&e)));
+                    }
+                    break;
+                }
+            };
+            let (chunk, rest) = pending.split_at(size);
+            pending = rest;
+
+            let body: Vec<&SearchQuery> = chunk.iter().map(|&idx| &queries[idx]).collect();
+
+            let sent = options
+                .run(self.send_batch(&url, &body, options.timeout))
+                .await;
+
+            match sent {
+                Ok(items) => {
+                    let charged = chunk
+                        .iter()
+                        .enumerate()
+                        .map(|(i, &idx)| Self::batch_item_credits(items.get(i), &queries[idx]))
+                        .sum();
+                    self.settle(reservation, charged);
+                    let mut items = items.into_iter();
+                    for &idx in chunk {
+                        let result = match items.next() {
+                            Some(item) => Self::parse_batch_item(item),
+                            None => Err(crate::core::SerperError::api_error(
+                                "Batch response is missing a result for this query",
+                            )),
+                        };
+                        if let Ok(response) = &result {
+                            self.store(&queries[idx], response).await;
+                        }
+                        results[idx] = Some(result);
+                    }
+                }
+                Err(e) => {
+                    self.settle_failure(reservation, &e);
+                    for &idx in chunk {
+                        results[idx] = Some(Err(Self::batch_error(&e)));
+                    }
+                }
+            }
+        }
+
+        results
+            .into_iter()
+            .map(|result| result.expect("every query has a result"))
+            .collect()
+    }
+
     /// Executes multiple search queries concurrently
     ///
     /// # Arguments
     ///
     /// * `queries` - The search queries to execute
-    /// * `max_concurrent` - Maximum number of concurrent requests
+    /// * `max_concurrent` - Maximum number of concurrent requests (0 is treated as 1)
     ///
     /// # Returns
     ///
@@ -131,16 +506,15 @@ impl SerperHttpClient {
         queries: &[SearchQuery],
         max_concurrent: usize,
     ) -> Result<Vec<SearchResponse>> {
-        use std::sync::Arc;
         use tokio::sync::Semaphore;
 
-        let semaphore = Arc::new(Semaphore::new(max_concurrent));
+        let semaphore = Arc::new(Semaphore::new(max_concurrent.max(1)));
         let mut handles = Vec::new();
 
         for query in queries {
             let semaphore = Arc::clone(&semaphore);
             let query = query.clone();
-            let client = self.clone_for_concurrent();
+            let client = self.clone();
 
             let handle = tokio::spawn(async move {
                 let _permit = semaphore.acquire().await.unwrap();
@@ -161,31 +535,379 @@ impl SerperHttpClient {
         Ok(results)
     }
 
+    /// Sends one batch request and returns the raw response items
+    async fn send_batch(
+        &self,
+        url: &str,
+        body: &[&SearchQuery],
+        timeout: Option<Duration>,
+    ) -> Result<Vec<serde_json::Value>> {
+        self.post_json(url, body, timeout).await?.json()
+    }
+
+    /// Reserves the credits a request costs, if there is a credit budget
+    ///
+    /// # Returns
+    ///
+    /// The reservation, `None` without a budget, or
+    /// `SerperError::BudgetExceeded` if fewer credits are left
+    fn reserve(&self, credits: u64) -> Result<Option<Reservation>> {
+        self.inner
+            .credit_budget
+            .as_ref()
+            .map(|budget| budget.reserve_exact(credits))
+            .transpose()
+    }
+
+    /// Reserves credits for the longest prefix of `candidates` the budget can pay for
+    ///
+    /// # Arguments
+    ///
+    /// * `queries` - All queries of the batch
+    /// * `candidates` - Indices of the queries that may go in the next chunk
+    ///
+    /// # Returns
+    ///
+    /// The number of queries in the chunk and its reservation (`None` without
+    /// a budget), or `SerperError::BudgetExceeded` if not even the first
+    /// query fits
+    fn reserve_chunk(
+        &self,
+        queries: &[SearchQuery],
+        candidates: &[usize],
+    ) -> Result<(usize, Option<Reservation>)> {
+        let Some(budget) = &self.inner.credit_budget else {
+            return Ok((candidates.len(), None));
+        };
+
+        let costs: Vec<u64> = candidates
+            .iter()
+            .map(|&idx| Self::query_credits(&queries[idx]))
+            .collect();
+        let mut reservation = budget.reserve(costs.iter().sum())?;
+
+        let mut size = 0;
+        let mut cost = 0;
+        for &query_cost in &costs {
+            if cost + query_cost > reservation.credits() {
+                break;
+            }
+            cost += query_cost;
+            size += 1;
+        }
+
+        if size == 0 {
+            // Some credits are left, but fewer than the first query costs
+            budget.settle(reservation, 0);
+            return budget
+                .reserve_exact(costs[0])
+                .map(|reservation| (1, Some(reservation)));
+        }
+        budget.shrink(&mut reservation, cost);
+        Ok((size, Some(reservation)))
+    }
+
+    /// Replaces a reservation by the credits actually charged
+    fn settle(&self, reservation: Option<Reservation>, charged: u64) {
+        if let (Some(budget), Some(reservation)) = (&self.inner.credit_budget, reservation) {
+            budget.settle(reservation, charged);
+        }
+    }
+
+    /// Settles the reservation of a failed request
+    ///
+    /// The credits are given back only if the request never reached the
+    /// server; otherwise Serper may have charged for it.
+    fn settle_failure(&self, reservation: Option<Reservation>, error: &SerperError) {
+        let reserved = reservation.as_ref().map_or(0, Reservation::credits);
+        let charged = if Self::never_sent(error) { 0 } else { reserved };
+        self.settle(reservation, charged);
+    }
+
+    /// Checks if a failed request cannot have reached the server
+    fn never_sent(error: &SerperError) -> bool {
+        match error {
+            SerperError::CircuitOpen
+            | SerperError::BudgetExceeded { .. }
+            | SerperError::Config { .. }
+            | SerperError::Validation { .. } => true,
+            SerperError::Request(error) => error.is_connect() || error.is_builder(),
+            _ => false,
+        }
+    }
+
+    /// Gets the credits a query costs: two when it asks for more than 10 results
+    fn query_credits(query: &SearchQuery) -> u64 {
+        if query.num.is_some_and(|num| num > 10) {
+            2
+        } else {
+            1
+        }
+    }
+
+    /// Gets the credits charged for one item of a batch response
+    ///
+    /// Missing items and items without a `credits` field cost what their
+    /// query was reserved for.
+    fn batch_item_credits(item: Option<&serde_json::Value>, query: &SearchQuery) -> u64 {
+        item.and_then(|item| item.get("credits"))
+            .and_then(serde_json::Value::as_u64)
+            .unwrap_or_else(|| Self::query_credits(query))
+    }
+
+    /// Sends an authenticated JSON POST through the transport
+    ///
+    /// Error statuses are converted into errors. With a key pool, a key that
+    /// is rejected or out of credits is marked exhausted and the request is
+    /// sent again with the next available key; once none is left, the call
+    /// fails with `SerperError::KeyPoolExhausted`.
+    async fn post_json<T: Serialize + ?Sized>(
+        &self,
+        url: &str,
+        body: &T,
+        timeout: Option<Duration>,
+    ) -> Result<Response> {
+        let mut request = Request::post_json(url, body)?;
+        request.timeout = timeout;
+
+        let Some(pool) = &self.inner.key_pool else {
+            return self.send_with_key(request, &self.inner.api_key).await;
+        };
+
+        loop {
+            let (index, api_key) = pool.acquire().ok_or(SerperError::KeyPoolExhausted {
+                keys: pool.len(),
+                last_error: None,
+            })?;
+
+            match self.send_with_key(request.clone(), api_key).await {
+                Err(e) if e.is_auth_error() || e.is_out_of_credits() => {
+                    pool.mark_exhausted(index);
+                    if pool.available() == 0 {
+                        return Err(SerperError::KeyPoolExhausted {
+                            keys: pool.len(),
+                            last_error: Some(Box::new(e)),
+                        });
+                    }
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Sends a request authenticated with `api_key`
+    async fn send_with_key(&self, request: Request, api_key: &ApiKey) -> Result<Response> {
+        let request = request.with_header(API_KEY_HEADER, api_key.as_str());
+        self.inner.transport.send(request).await?.error_for_status()
+    }
+
+    /// Copies a failed batch request's error for one of its queries
+    ///
+    /// HTTP status errors keep their structure; other errors are described
+    /// in an API error.
+    fn batch_error(error: &SerperError) -> SerperError {
+        match error {
+            SerperError::Http { .. }
+            | SerperError::RateLimited { .. }
+            | SerperError::InvalidApiKey
+            | SerperError::CircuitOpen
+            | SerperError::BudgetExceeded { .. }
+            | SerperError::KeyPoolExhausted { .. }
+            | SerperError::Cancelled
+            | SerperError::Timeout { .. } => error.duplicate(),
+            other => SerperError::api_error(format!("Batch request failed: {}", other)),
+        }
+    }
+
+    /// Parses and validates a single item of a batch response
+    fn parse_batch_item(item: serde_json::Value) -> Result<SearchResponse> {
+        let search_response: SearchResponse = serde_json::from_value(item)?;
+        ResponseParser::validate_response(&search_response)?;
+        Ok(search_response)
+    }
+
+    /// Executes multiple search queries concurrently as a stream
+    ///
+    /// Results are yielded as soon as each request completes, so they arrive
+    /// in completion order rather than input order. Each item carries the
+    /// index of its query in `queries`.
+    ///
+    /// # Arguments
+    ///
+    /// * `queries` - The search queries to execute
+    /// * `max_concurrent` - Maximum number of requests in flight
+    ///
+    /// # Returns
+    ///
+    /// A stream of `(index, result)` pairs, one per query
+    pub fn search_stream<'a>(
+        &'a self,
+        queries: &'a [SearchQuery],
+        max_concurrent: usize,
+    ) -> impl Stream<Item = (usize, Result<SearchResponse>)> + 'a {
+        stream::iter(queries.iter().enumerate())
+            .map(move |(index, query)| async move { (index, self.search(query).await) })
+            .buffer_unordered(max_concurrent.max(1))
+    }
+
+    /// Executes multiple search queries concurrently, keeping partial results
+    ///
+    /// Unlike [`SerperHttpClient::search_concurrent`], a failed query does not
+    /// abort the batch: every query gets its own result in the report.
+    ///
+    /// # Arguments
+    ///
+    /// * `queries` - The search queries to execute
+    /// * `max_concurrent` - Maximum number of concurrent requests (0 is treated as 1)
+    ///
+    /// # Returns
+    ///
+    /// A report with per-query results and timing, in input order
+    pub async fn search_concurrent_report(
+        &self,
+        queries: &[SearchQuery],
+        max_concurrent: usize,
+    ) -> BatchReport {
+        self.search_concurrent_report_with_options(queries, max_concurrent, RequestOptions::new())
+            .await
+    }
+
+    /// Executes multiple search queries concurrently with per-call options
+    ///
+    /// Works like [`SerperHttpClient::search_concurrent_report`], with
+    /// `options.timeout` as the deadline of each request, retries included
+    /// and queue time excluded. When `options.cancel`
+    /// fires, queries in flight or still queued fail with
+    /// `SerperError::Cancelled`; completed results are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `queries` - The search queries to execute
+    /// * `max_concurrent` - Maximum number of concurrent requests (0 is treated as 1)
+    /// * `options` - Timeout and cancellation token for this call
+    ///
+    /// # Returns
+    ///
+    /// A report with per-query results and timing, in input order
+    pub async fn search_concurrent_report_with_options(
+        &self,
+        queries: &[SearchQuery],
+        max_concurrent: usize,
+        options: RequestOptions,
+    ) -> BatchReport {
+        use std::time::Instant;
+        use tokio::sync::Semaphore;
+
+        let started = Instant::now();
+        let semaphore = Arc::new(Semaphore::new(max_concurrent.max(1)));
+        let mut handles = Vec::new();
+
+        for (index, query) in queries.iter().enumerate() {
+            let semaphore = Arc::clone(&semaphore);
+            let query = query.clone();
+            let client = self.clone();
+
+            let options = options.clone();
+
+            let handle = tokio::spawn(async move {
+                // Set once a slot is free, so queue time is not counted
+                let mut query_started = None;
+                let result = options
+                    .cancellable(async {
+                        let _permit = semaphore.acquire().await.unwrap();
+                        query_started = Some(Instant::now());
+                        options
+                            .run(client.search_cached(&query, None, options.timeout))
+                            .await
+                    })
+                    .await;
+                let elapsed = query_started.map_or(Duration::ZERO, |started| started.elapsed());
+                (result, elapsed)
+            });
+
+            handles.push((index, handle));
+        }
+
+        let mut items = Vec::with_capacity(queries.len());
+        for (index, handle) in handles {
+            let (result, elapsed) = handle.await.unwrap_or_else(|e| {
+                (
+                    Err(crate::core::SerperError::config_error(format!(
+                        "Task join error: {}",
+                        e
+                    ))),
+                    Duration::ZERO,
+                )
+            });
+
+            items.push(BatchItem {
+                index,
+                query: queries[index].clone(),
+                result,
+                elapsed,
+            });
+        }
+
+        BatchReport::new(items, started.elapsed())
+    }
+
     /// Gets the API key (for debugging/logging purposes)
     pub fn api_key(&self) -> &ApiKey {
-        &self.api_key
+        &self.inner.api_key
     }
 
     /// Gets the base URL
     pub fn base_url(&self) -> &BaseUrl {
-        &self.base_url
+        &self.inner.base_url
     }
 
     /// Gets the transport configuration
     pub fn transport_config(&self) -> &TransportConfig {
-        self.transport.config()
+        &self.inner.config
     }
 
-    /// Helper method to clone the client for concurrent operations
-    ///
-    /// This creates a new HTTP transport but reuses the API key and base URL
-    fn clone_for_concurrent(&self) -> Self {
-        Self {
-            transport: HttpTransport::with_config(self.transport.config().clone())
-                .expect("Failed to clone transport"),
-            api_key: self.api_key.clone(),
-            base_url: self.base_url.clone(),
-        }
+    /// Gets the circuit breaker state, if a circuit breaker is enabled
+    pub fn circuit_state(&self) -> Option<CircuitState> {
+        self.inner
+            .circuit_breaker
+            .as_ref()
+            .map(|breaker| breaker.state())
+    }
+
+    /// Gets the response cache, if caching is enabled
+    pub fn cache(&self) -> Option<&ResponseCache> {
+        self.inner.cache.as_ref()
+    }
+
+    /// Checks if concurrent identical searches share one request
+    pub fn coalesces_requests(&self) -> bool {
+        self.inner.in_flight.is_some()
+    }
+
+    /// Gets the hedging counters, if hedging is enabled
+    pub fn hedge_stats(&self) -> Option<HedgeStats> {
+        self.inner.hedger.as_ref().map(Hedger::stats)
+    }
+
+    /// Gets the API key pool, if one is configured
+    pub fn key_pool(&self) -> Option<&ApiKeyPool> {
+        self.inner.key_pool.as_ref()
+    }
+
+    /// Gets the credit budget applied to this client's requests, if any
+    pub fn credit_budget(&self) -> Option<&Arc<CreditBudget>> {
+        self.inner.credit_budget.as_ref()
+    }
+
+    /// Gets the persistent response cache, if enabled
+    #[cfg(feature = "disk-cache")]
+    pub fn disk_cache(&self) -> Option<&DiskCache> {
+        self.inner.disk_cache.as_deref()
+    }
+
+    /// Gets the rate limiter applied to this client's requests, if any
+    pub fn rate_limiter(&self) -> Option<&Arc<RateLimiter>> {
+        self.transport_config().rate_limiter.as_ref()
     }
 }
 
@@ -194,6 +916,15 @@ pub struct SerperHttpClientBuilder {
     api_key: Option<ApiKey>,
     base_url: Option<BaseUrl>,
     transport_config: TransportConfig,
+    transport: Option<Box<dyn Transport>>,
+    middleware: Vec<Arc<dyn Middleware>>,
+    cache: Option<CacheConfig>,
+    #[cfg(feature = "disk-cache")]
+    disk_cache: Option<DiskCache>,
+    coalesce_requests: bool,
+    hedging: Option<HedgeConfig>,
+    key_pool: Option<ApiKeyPool>,
+    credit_budget: Option<Arc<CreditBudget>>,
 }
 
 impl SerperHttpClientBuilder {
@@ -203,6 +934,15 @@ impl SerperHttpClientBuilder {
             api_key: None,
             base_url: None,
             transport_config: TransportConfig::new(),
+            transport: None,
+            middleware: Vec::new(),
+            cache: None,
+            #[cfg(feature = "disk-cache")]
+            disk_cache: None,
+            coalesce_requests: false,
+            hedging: None,
+            key_pool: None,
+            credit_budget: None,
         }
     }
 
@@ -212,6 +952,15 @@ impl SerperHttpClientBuilder {
         self
     }
 
+    /// Spreads requests over a pool of API keys
+    ///
+    /// Replaces the single API key for requests; [`SerperHttpClient::api_key`]
+    /// reports the pool's first key unless an API key is also set.
+    pub fn api_key_pool(mut self, key_pool: ApiKeyPool) -> Self {
+        self.key_pool = Some(key_pool);
+        self
+    }
+
     /// Sets the base URL
     pub fn base_url(mut self, base_url: BaseUrl) -> Self {
         self.base_url = Some(base_url);
@@ -225,7 +974,7 @@ impl SerperHttpClientBuilder {
     }
 
     /// Sets the request timeout
-    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+    pub fn timeout(mut self, timeout: Duration) -> Self {
         self.transport_config = self.transport_config.with_timeout(timeout);
         self
     }
@@ -236,15 +985,157 @@ impl SerperHttpClientBuilder {
         self
     }
 
+    /// Sets the user agent
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.transport_config = self.transport_config.with_user_agent(user_agent.into());
+        self
+    }
+
+    /// Enables retries of transient failures
+    pub fn retry(mut self, retry: RetryConfig) -> Self {
+        self.transport_config = self.transport_config.with_retry(retry);
+        self
+    }
+
+    /// Sets a rate limiter, which may be shared with other clients
+    pub fn rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.transport_config = self.transport_config.with_rate_limiter(rate_limiter);
+        self
+    }
+
+    /// Enables a circuit breaker for this client's requests
+    pub fn circuit_breaker(mut self, circuit_breaker: CircuitBreakerConfig) -> Self {
+        self.transport_config = self.transport_config.with_circuit_breaker(circuit_breaker);
+        self
+    }
+
+    /// Sends requests through a custom transport instead of [`HttpTransport`]
+    ///
+    /// The timeout, default headers, retry policy, rate limiter and circuit
+    /// breaker still apply around the custom transport; the user agent is
+    /// left to it.
+    pub fn transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Some(Box::new(transport));
+        self
+    }
+
+    /// Adds a middleware to the request chain
+    ///
+    /// Middleware runs in the order it is added before each request, and in
+    /// reverse order after each response.
+    pub fn middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Adds a hook that can modify each request before it is sent
+    pub fn before_request<F>(self, hook: F) -> Self
+    where
+        F: Fn(&mut Request) -> Result<()> + Send + Sync + 'static,
+    {
+        self.middleware(BeforeRequest(hook))
+    }
+
+    /// Adds a hook that sees each successful response and how long it took
+    pub fn after_response<F>(self, hook: F) -> Self
+    where
+        F: Fn(&Request, &mut Response, Duration) -> Result<()> + Send + Sync + 'static,
+    {
+        self.middleware(AfterResponse(hook))
+    }
+
+    /// Enables an in-memory response cache
+    pub fn cache(mut self, cache: CacheConfig) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Enables a persistent response cache, consulted after the in-memory one
+    #[cfg(feature = "disk-cache")]
+    pub fn disk_cache(mut self, disk_cache: DiskCache) -> Self {
+        self.disk_cache = Some(disk_cache);
+        self
+    }
+
+    /// Sets whether concurrent identical searches share one request
+    ///
+    /// Disabled by default. When enabled, while a search is in flight, other
+    /// `search` calls with an identical query wait for its outcome instead of
+    /// sending their own request, and all of them get the same response or
+    /// error. Batch searches are not coalesced.
+    pub fn coalesce_requests(mut self, enabled: bool) -> Self {
+        self.coalesce_requests = enabled;
+        self
+    }
+
+    /// Caps the credits this client may spend, possibly shared with other clients
+    ///
+    /// Once the budget is used up, requests fail with
+    /// `SerperError::BudgetExceeded` without being sent.
+    pub fn credit_budget(mut self, credit_budget: Arc<CreditBudget>) -> Self {
+        self.credit_budget = Some(credit_budget);
+        self
+    }
+
+    /// Enables hedged searches
+    ///
+    /// A single search that is slower than recent searches (see
+    /// [`HedgeConfig`]) gets one duplicate request, and the first successful
+    /// copy is used. Batch requests are never hedged.
+    pub fn hedging(mut self, hedging: HedgeConfig) -> Self {
+        self.hedging = Some(hedging);
+        self
+    }
+
     /// Builds the HTTP client
+    ///
+    /// Uses the custom transport if one was set, otherwise an
+    /// [`HttpTransport`] built from the transport configuration, and wraps it
+    /// in the middleware chain if there is one.
     pub fn build(self) -> Result<SerperHttpClient> {
         let api_key = self
             .api_key
+            .or_else(|| self.key_pool.as_ref().map(|pool| pool.keys()[0].clone()))
             .ok_or_else(|| crate::core::SerperError::config_error("API key is required"))?;
 
         let base_url = self.base_url.unwrap_or_default();
 
-        SerperHttpClient::with_config(api_key, base_url, self.transport_config)
+        let (transport, config, circuit_breaker): (Box<dyn Transport>, _, _) = match self.transport
+        {
+            Some(transport) => {
+                let transport = ConfiguredTransport::new(transport, self.transport_config.clone());
+                let circuit_breaker = transport.circuit_breaker_handle();
+                (Box::new(transport), self.transport_config, circuit_breaker)
+            }
+            None => {
+                let transport = HttpTransport::with_config(self.transport_config.clone())?;
+                let circuit_breaker = transport.circuit_breaker_handle();
+                (Box::new(transport), self.transport_config, circuit_breaker)
+            }
+        };
+
+        let transport: Box<dyn Transport> = if self.middleware.is_empty() {
+            transport
+        } else {
+            Box::new(MiddlewareTransport::new(transport).with_all(self.middleware))
+        };
+
+        Ok(SerperHttpClient {
+            inner: Arc::new(ClientInner {
+                transport,
+                config,
+                circuit_breaker,
+                cache: self.cache.map(ResponseCache::new),
+                #[cfg(feature = "disk-cache")]
+                disk_cache: self.disk_cache.map(Arc::new),
+                in_flight: self.coalesce_requests.then(SingleFlight::new),
+                hedger: self.hedging.map(Hedger::new),
+                key_pool: self.key_pool,
+                credit_budget: self.credit_budget,
+                api_key,
+                base_url,
+            }),
+        })
     }
 }
 
@@ -267,16 +1158,30 @@ mod tests {
         let builder = SerperHttpClientBuilder::new()
             .api_key(api_key.clone())
             .base_url(base_url.clone())
-            .timeout(std::time::Duration::from_secs(60))
+            .timeout(Duration::from_secs(60))
             .header("Custom", "Value");
 
         let client = builder.build().unwrap();
         assert_eq!(client.api_key().as_str(), "test-key");
         assert_eq!(client.base_url().as_str(), "https://test.api.com");
-        assert_eq!(
-            client.transport_config().timeout,
-            std::time::Duration::from_secs(60)
-        );
+        assert_eq!(client.transport_config().timeout, Duration::from_secs(60));
+    }
+
+    #[test]
+    fn test_custom_transport_keeps_transport_config() {
+        let api_key = ApiKey::new("test-key".to_string()).unwrap();
+        let client = SerperHttpClientBuilder::new()
+            .api_key(api_key)
+            .transport(HttpTransport::new().unwrap())
+            .timeout(Duration::from_secs(5))
+            .rate_limiter(Arc::new(RateLimiter::per_second(10).unwrap()))
+            .circuit_breaker(CircuitBreakerConfig::new())
+            .build()
+            .unwrap();
+
+        assert_eq!(client.transport_config().timeout, Duration::from_secs(5));
+        assert!(client.rate_limiter().is_some());
+        assert_eq!(client.circuit_state(), Some(CircuitState::Closed));
     }
 
     #[test]
@@ -288,6 +1193,18 @@ mod tests {
         assert_eq!(client.base_url().as_str(), "https://google.serper.dev");
     }
 
+    #[test]
+    fn test_client_clone_shares_state() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<SerperHttpClient>();
+
+        let api_key = ApiKey::new("test-key".to_string()).unwrap();
+        let client = SerperHttpClient::new(api_key).unwrap();
+        let cloned = client.clone();
+
+        assert!(Arc::ptr_eq(&client.inner, &cloned.inner));
+    }
+
     #[test]
     fn test_builder_missing_api_key() {
         let builder = SerperHttpClientBuilder::new();
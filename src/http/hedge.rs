@@ -0,0 +1,383 @@
+use crate::core::Result;
+/// Hedged request module
+///
+/// This module sends a duplicate of a slow request and takes whichever copy
+/// answers first, trading a capped number of extra credits for lower tail
+/// latency.
+use futures::future::{self, Either};
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Default latency percentile after which a request is hedged
+pub const DEFAULT_HEDGE_PERCENTILE: f64 = 0.95;
+
+/// Default hedge delay used until enough latencies have been observed
+pub const DEFAULT_HEDGE_INITIAL_DELAY: Duration = Duration::from_secs(1);
+
+/// Default maximum number of hedge requests per minute
+pub const DEFAULT_MAX_HEDGES_PER_MINUTE: u32 = 10;
+
+/// Number of recent latencies the threshold is computed from
+const LATENCY_WINDOW: usize = 100;
+
+/// Number of latencies needed before the percentile replaces the initial delay
+const MIN_SAMPLES: usize = 20;
+
+/// Hedging policy configuration
+///
+/// A search that has not answered after the configured latency percentile
+/// of recent searches gets one duplicate request; the first successful copy
+/// wins and the other is dropped. Every hedge may cost an extra credit, so
+/// hedges are capped per minute.
+///
+/// # Example
+///
+/// ```rust
+/// use serper_sdk::http::HedgeConfig;
+/// use serper_sdk::search::service::SearchServiceBuilder;
+/// use std::time::Duration;
+///
+/// fn main() -> serper_sdk::core::Result<()> {
+///     let service = SearchServiceBuilder::new()
+///         .api_key("demo-key-for-docs")
+///         .hedging(
+///             HedgeConfig::new()
+///                 .with_initial_delay(Duration::from_millis(800))
+///                 .with_max_hedges_per_minute(30),
+///         )
+///         .build()?;
+///
+///     assert_eq!(service.hedge_stats().unwrap().hedged, 0);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct HedgeConfig {
+    /// Latency percentile (between 0 and 1) after which a request is hedged
+    pub percentile: f64,
+    /// Hedge delay used until enough latencies have been observed
+    pub initial_delay: Duration,
+    /// Maximum number of hedge requests, and so extra credits, per minute
+    pub max_hedges_per_minute: u32,
+}
+
+impl HedgeConfig {
+    /// Creates a policy hedging after the p95 latency, at most 10 times a minute
+    pub fn new() -> Self {
+        Self {
+            percentile: DEFAULT_HEDGE_PERCENTILE,
+            initial_delay: DEFAULT_HEDGE_INITIAL_DELAY,
+            max_hedges_per_minute: DEFAULT_MAX_HEDGES_PER_MINUTE,
+        }
+    }
+
+    /// Sets the latency percentile, clamped between 0.5 and 1
+    pub fn with_percentile(mut self, percentile: f64) -> Self {
+        self.percentile = percentile.clamp(0.5, 1.0);
+        self
+    }
+
+    /// Sets the hedge delay used until enough latencies have been observed
+    pub fn with_initial_delay(mut self, delay: Duration) -> Self {
+        self.initial_delay = delay;
+        self
+    }
+
+    /// Sets the maximum number of hedge requests per minute
+    pub fn with_max_hedges_per_minute(mut self, max: u32) -> Self {
+        self.max_hedges_per_minute = max;
+        self
+    }
+}
+
+impl Default for HedgeConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Hedging counters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HedgeStats {
+    /// Requests sent through the hedging policy
+    pub requests: u64,
+    /// Requests that got a duplicate
+    pub hedged: u64,
+    /// Hedged requests answered by the duplicate
+    pub hedge_wins: u64,
+    /// Slow requests not hedged because the per-minute cap was reached
+    pub denied: u64,
+}
+
+/// Runs calls under a hedging policy, tracking their latencies
+#[derive(Debug)]
+pub(crate) struct Hedger {
+    config: HedgeConfig,
+    state: Mutex<HedgeState>,
+}
+
+/// Mutable state of a hedger
+#[derive(Debug, Default)]
+struct HedgeState {
+    /// Most recent latencies of successful first copies
+    latencies: VecDeque<Duration>,
+    /// Start times of the hedges sent in the last minute
+    recent_hedges: VecDeque<Instant>,
+    stats: HedgeStats,
+}
+
+impl Hedger {
+    /// Creates a hedger with no observed latencies
+    pub(crate) fn new(config: HedgeConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(HedgeState::default()),
+        }
+    }
+
+    /// Gets the counters
+    pub(crate) fn stats(&self) -> HedgeStats {
+        self.lock().stats
+    }
+
+    /// Gets how long to wait for a request before hedging it
+    ///
+    /// This is the configured percentile of recent latencies, or the initial
+    /// delay until enough latencies have been observed.
+    pub(crate) fn delay(&self) -> Duration {
+        let state = self.lock();
+        if state.latencies.len() < MIN_SAMPLES {
+            return self.config.initial_delay;
+        }
+
+        let mut sorted: Vec<Duration> = state.latencies.iter().copied().collect();
+        sorted.sort();
+        let rank = (self.config.percentile * sorted.len() as f64).ceil() as usize;
+        sorted[rank.clamp(1, sorted.len()) - 1]
+    }
+
+    /// Runs `call`, starting a second copy if the first is slower than the delay
+    ///
+    /// Only the latencies of successful first copies are recorded, so fast
+    /// failures and duplicates do not lower the hedge delay.
+    ///
+    /// # Arguments
+    ///
+    /// * `call` - Starts one copy of the request
+    ///
+    /// # Returns
+    ///
+    /// The first successful outcome, or the last error if both copies fail
+    pub(crate) async fn run<T, F, Fut>(&self, call: F) -> Result<T>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let started = Instant::now();
+        self.lock().stats.requests += 1;
+
+        let primary = Box::pin(call());
+        let timer = Box::pin(tokio::time::sleep(self.delay()));
+        let primary = match future::select(primary, timer).await {
+            Either::Left((result, _)) => {
+                self.record_primary(started, &result);
+                return result;
+            }
+            Either::Right((_, primary)) => primary,
+        };
+
+        if !self.try_hedge() {
+            let result = primary.await;
+            self.record_primary(started, &result);
+            return result;
+        }
+
+        let hedge = Box::pin(call());
+        let (result, hedge_won) = match future::select(primary, hedge).await {
+            Either::Left((Ok(value), _)) => (Ok(value), false),
+            Either::Right((Ok(value), _)) => (Ok(value), true),
+            // The first copy failed; the other one may still succeed
+            Either::Left((Err(_), hedge)) => (hedge.await, true),
+            Either::Right((Err(_), primary)) => (primary.await, false),
+        };
+
+        if !hedge_won {
+            self.record_primary(started, &result);
+        }
+        if hedge_won && result.is_ok() {
+            self.lock().stats.hedge_wins += 1;
+        }
+        result
+    }
+
+    /// Takes a hedge from the per-minute allowance
+    ///
+    /// # Returns
+    ///
+    /// True if a hedge may be sent
+    fn try_hedge(&self) -> bool {
+        let mut state = self.lock();
+        let now = Instant::now();
+        while state
+            .recent_hedges
+            .front()
+            .is_some_and(|sent| now.duration_since(*sent) >= Duration::from_secs(60))
+        {
+            state.recent_hedges.pop_front();
+        }
+
+        if state.recent_hedges.len() >= self.config.max_hedges_per_minute as usize {
+            state.stats.denied += 1;
+            return false;
+        }
+        state.recent_hedges.push_back(now);
+        state.stats.hedged += 1;
+        true
+    }
+
+    /// Records the latency of a first copy started at `started`, if it succeeded
+    fn record_primary<T>(&self, started: Instant, result: &Result<T>) {
+        if result.is_ok() {
+            self.record(started.elapsed());
+        }
+    }
+
+    /// Records the latency of a successful request
+    fn record(&self, latency: Duration) {
+        let mut state = self.lock();
+        if state.latencies.len() == LATENCY_WINDOW {
+            state.latencies.pop_front();
+        }
+        state.latencies.push_back(latency);
+    }
+
+    /// Locks the hedger state, recovering from a poisoned lock
+    fn lock(&self) -> std::sync::MutexGuard<'_, HedgeState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::SerperError;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn hedger(max_hedges_per_minute: u32) -> Hedger {
+        Hedger::new(
+            HedgeConfig::new()
+                .with_initial_delay(Duration::from_millis(20))
+                .with_max_hedges_per_minute(max_hedges_per_minute),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_fast_requests_are_not_hedged() {
+        let hedger = hedger(10);
+        let calls = AtomicUsize::new(0);
+
+        let result = hedger
+            .run(|| async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Ok(1)
+            })
+            .await;
+
+        assert_eq!(result.unwrap(), 1);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(hedger.stats().hedged, 0);
+    }
+
+    #[tokio::test]
+    async fn test_slow_request_is_hedged_and_duplicate_wins() {
+        let hedger = hedger(10);
+        let calls = AtomicUsize::new(0);
+        let call = || async {
+            let copy = calls.fetch_add(1, Ordering::SeqCst);
+            if copy == 0 {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+            }
+            Ok(copy)
+        };
+
+        let started = Instant::now();
+        assert_eq!(hedger.run(call).await.unwrap(), 1);
+        assert!(started.elapsed() < Duration::from_secs(1));
+
+        let stats = hedger.stats();
+        assert_eq!((stats.requests, stats.hedged, stats.hedge_wins), (1, 1, 1));
+    }
+
+    #[tokio::test]
+    async fn test_failed_copy_falls_back_to_the_other() {
+        let hedger = hedger(10);
+        let calls = AtomicUsize::new(0);
+        let call = || async {
+            if calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                tokio::time::sleep(Duration::from_millis(60)).await;
+                Ok("primary")
+            } else {
+                Err(SerperError::api_error("hedge failed"))
+            }
+        };
+
+        assert_eq!(hedger.run(call).await.unwrap(), "primary");
+        assert_eq!(hedger.stats().hedge_wins, 0);
+    }
+
+    #[tokio::test]
+    async fn test_hedges_are_capped_per_minute() {
+        let hedger = hedger(1);
+        let call = || async {
+            tokio::time::sleep(Duration::from_millis(40)).await;
+            Ok(())
+        };
+
+        hedger.run(call).await.unwrap();
+        hedger.run(call).await.unwrap();
+
+        let stats = hedger.stats();
+        assert_eq!((stats.requests, stats.hedged, stats.denied), (2, 1, 1));
+    }
+
+    #[tokio::test]
+    async fn test_only_successful_primary_latencies_are_recorded() {
+        let hedger = hedger(10);
+
+        let failed: Result<()> = hedger
+            .run(|| async { Err(SerperError::api_error("fast failure")) })
+            .await;
+        assert!(failed.is_err());
+        assert!(hedger.lock().latencies.is_empty());
+
+        // The duplicate answers first, so the primary's latency is unknown
+        let calls = AtomicUsize::new(0);
+        let call = || async {
+            if calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+            }
+            Ok(())
+        };
+        hedger.run(call).await.unwrap();
+        assert!(hedger.lock().latencies.is_empty());
+
+        hedger.run(|| async { Ok(()) }).await.unwrap();
+        assert_eq!(hedger.lock().latencies.len(), 1);
+    }
+
+    #[test]
+    fn test_delay_follows_latency_percentile() {
+        let hedger = hedger(10);
+        assert_eq!(hedger.delay(), Duration::from_millis(20));
+
+        for ms in 1..=MIN_SAMPLES as u64 {
+            hedger.record(Duration::from_millis(ms * 10));
+        }
+        // p95 of 10ms..=200ms is the 19th of 20 samples
+        assert_eq!(hedger.delay(), Duration::from_millis(190));
+    }
+}
@@ -0,0 +1,245 @@
+use crate::core::{Result, SerperError, types::ApiKey};
+/// API key pool module
+///
+/// This module spreads requests over several API keys and fails over to
+/// another key when one is rejected or runs out of credits.
+use std::sync::Mutex;
+
+/// How a key pool picks the key for each request
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum KeyStrategy {
+    /// Cycles through the available keys in order
+    #[default]
+    RoundRobin,
+    /// Uses the available key that has sent the fewest requests
+    LeastUsed,
+    /// Uses the first available key until it is exhausted
+    Failover,
+}
+
+/// Usage of one key in a pool
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KeyStats {
+    /// Requests sent with the key
+    pub requests: u64,
+    /// Whether the key was rejected or ran out of credits
+    pub exhausted: bool,
+}
+
+/// Pool of API keys shared by a client's requests
+///
+/// A key is marked exhausted when Serper rejects it (HTTP 401/403) or
+/// reports that it is out of credits; the request is then retried with the
+/// next available key. Exhausted keys stay out of rotation until restored,
+/// and requests fail with `SerperError::KeyPoolExhausted` while none is left.
+///
+/// # Example
+///
+/// ```rust
+/// use serper_sdk::http::KeyStrategy;
+/// use serper_sdk::search::service::SearchServiceBuilder;
+///
+/// fn main() -> serper_sdk::core::Result<()> {
+///     let service = SearchServiceBuilder::new()
+///         .api_keys(["team-a-key", "team-b-key"], KeyStrategy::RoundRobin)
+///         .build()?;
+///
+///     let pool = service.key_pool().unwrap();
+///     assert_eq!(pool.len(), 2);
+///     assert_eq!(pool.available(), 2);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ApiKeyPool {
+    keys: Vec<ApiKey>,
+    strategy: KeyStrategy,
+    state: Mutex<PoolState>,
+}
+
+/// Mutable state of a key pool
+#[derive(Debug)]
+struct PoolState {
+    stats: Vec<KeyStats>,
+    /// Index where the next round-robin search starts
+    next: usize,
+}
+
+impl ApiKeyPool {
+    /// Creates a pool
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The API keys, in failover order
+    /// * `strategy` - How keys are picked for each request
+    ///
+    /// # Returns
+    ///
+    /// Result containing the pool, or a configuration error if `keys` is empty
+    pub fn new(keys: Vec<ApiKey>, strategy: KeyStrategy) -> Result<Self> {
+        if keys.is_empty() {
+            return Err(SerperError::config_error(
+                "API key pool needs at least one key",
+            ));
+        }
+
+        Ok(Self {
+            state: Mutex::new(PoolState {
+                stats: vec![KeyStats::default(); keys.len()],
+                next: 0,
+            }),
+            keys,
+            strategy,
+        })
+    }
+
+    /// Gets the keys, in failover order
+    pub fn keys(&self) -> &[ApiKey] {
+        &self.keys
+    }
+
+    /// Gets the key selection strategy
+    pub fn strategy(&self) -> KeyStrategy {
+        self.strategy
+    }
+
+    /// Gets the number of keys
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Checks if the pool has no keys, which [`ApiKeyPool::new`] prevents
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Gets the number of keys that are not exhausted
+    pub fn available(&self) -> usize {
+        self.lock()
+            .stats
+            .iter()
+            .filter(|stats| !stats.exhausted)
+            .count()
+    }
+
+    /// Gets the usage of each key, in the order of [`ApiKeyPool::keys`]
+    pub fn stats(&self) -> Vec<KeyStats> {
+        self.lock().stats.clone()
+    }
+
+    /// Takes a key out of rotation
+    pub fn mark_exhausted(&self, index: usize) {
+        if let Some(stats) = self.lock().stats.get_mut(index) {
+            stats.exhausted = true;
+        }
+    }
+
+    /// Puts an exhausted key back into rotation, e.g. after a top-up
+    pub fn restore(&self, index: usize) {
+        if let Some(stats) = self.lock().stats.get_mut(index) {
+            stats.exhausted = false;
+        }
+    }
+
+    /// Puts every key back into rotation
+    pub fn restore_all(&self) {
+        for stats in &mut self.lock().stats {
+            stats.exhausted = false;
+        }
+    }
+
+    /// Picks the key for a request and counts the request against it
+    ///
+    /// # Returns
+    ///
+    /// The index and key to use, or `None` if every key is exhausted
+    pub(crate) fn acquire(&self) -> Option<(usize, &ApiKey)> {
+        let mut state = self.lock();
+        let count = state.stats.len();
+        let available = |index: &usize| !state.stats[*index].exhausted;
+
+        let index = match self.strategy {
+            KeyStrategy::RoundRobin => (0..count)
+                .map(|offset| (state.next + offset) % count)
+                .find(available),
+            KeyStrategy::LeastUsed => (0..count)
+                .filter(available)
+                .min_by_key(|index| state.stats[*index].requests),
+            KeyStrategy::Failover => (0..count).find(available),
+        }?;
+
+        state.next = (index + 1) % count;
+        state.stats[index].requests += 1;
+        Some((index, &self.keys[index]))
+    }
+
+    /// Locks the pool state, recovering from a poisoned lock
+    fn lock(&self) -> std::sync::MutexGuard<'_, PoolState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool(strategy: KeyStrategy) -> ApiKeyPool {
+        let keys = ["a", "b", "c"]
+            .iter()
+            .map(|key| ApiKey::new(key.to_string()).unwrap())
+            .collect();
+        ApiKeyPool::new(keys, strategy).unwrap()
+    }
+
+    fn picks(pool: &ApiKeyPool, n: usize) -> Vec<usize> {
+        (0..n).map(|_| pool.acquire().unwrap().0).collect()
+    }
+
+    #[test]
+    fn test_empty_pool_is_rejected() {
+        let error = ApiKeyPool::new(Vec::new(), KeyStrategy::RoundRobin).unwrap_err();
+        assert!(matches!(error, SerperError::Config { .. }));
+    }
+
+    #[test]
+    fn test_round_robin_skips_exhausted_keys() {
+        let pool = pool(KeyStrategy::RoundRobin);
+        assert_eq!(picks(&pool, 4), [0, 1, 2, 0]);
+
+        pool.mark_exhausted(2);
+        assert_eq!(picks(&pool, 3), [1, 0, 1]);
+        assert_eq!(pool.available(), 2);
+    }
+
+    #[test]
+    fn test_least_used_balances_requests() {
+        let pool = pool(KeyStrategy::LeastUsed);
+        pool.mark_exhausted(0);
+        assert_eq!(picks(&pool, 2), [1, 2]);
+
+        pool.restore(0);
+        assert_eq!(picks(&pool, 3), [0, 0, 1]);
+        assert_eq!(
+            pool.stats().iter().map(|s| s.requests).collect::<Vec<_>>(),
+            [2, 2, 1]
+        );
+    }
+
+    #[test]
+    fn test_failover_uses_next_key_when_exhausted() {
+        let pool = pool(KeyStrategy::Failover);
+        assert_eq!(picks(&pool, 2), [0, 0]);
+
+        pool.mark_exhausted(0);
+        pool.mark_exhausted(1);
+        assert_eq!(picks(&pool, 1), [2]);
+
+        pool.mark_exhausted(2);
+        assert!(pool.acquire().is_none());
+
+        pool.restore_all();
+        assert_eq!(pool.available(), 3);
+    }
+}
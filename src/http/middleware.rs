@@ -0,0 +1,301 @@
+use crate::core::{Result, SerperError};
+/// Request and response middleware module
+///
+/// This module provides hooks that run before each request and after each
+/// response, for dynamic headers, request signing, logging and metrics.
+use crate::http::transport::{Request, Response, Transport};
+use futures::future::BoxFuture;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Hooks around every request sent by a client
+///
+/// Every method has a default no-op implementation, so a middleware only
+/// overrides the hooks it needs. Static headers are better set with
+/// `TransportConfig::with_header`; middleware is for values computed per
+/// request.
+///
+/// Hooks run once per client call, outside the retry loop of `HttpTransport`,
+/// so `elapsed` includes any retries. Error statuses reach `on_error` as
+/// errors rather than `after_response`.
+///
+/// # Example
+///
+/// ```rust
+/// use serper_sdk::core::{Result, SerperError};
+/// use serper_sdk::http::{Middleware, Request, Response};
+/// use serper_sdk::search::service::SearchServiceBuilder;
+/// use std::time::Duration;
+///
+/// struct Timing;
+///
+/// impl Middleware for Timing {
+///     fn after_response(&self, request: &Request, _: &mut Response, elapsed: Duration) -> Result<()> {
+///         println!("{} took {:?}", request.url, elapsed);
+///         Ok(())
+///     }
+///
+///     fn on_error(&self, request: &Request, error: &SerperError, elapsed: Duration) {
+///         println!("{} failed after {:?}: {}", request.url, elapsed, error);
+///     }
+/// }
+///
+/// fn main() -> Result<()> {
+///     let _service = SearchServiceBuilder::new()
+///         .api_key("demo-key-for-docs")
+///         .before_request(|request| {
+///             request.headers.insert("X-Correlation-Id".to_string(), "abc-123".to_string());
+///             Ok(())
+///         })
+///         .middleware(Timing)
+///         .build()?;
+///     Ok(())
+/// }
+/// ```
+pub trait Middleware: Send + Sync {
+    /// Runs before the request is sent
+    ///
+    /// Returning an error aborts the request with that error.
+    fn before_request(&self, request: &mut Request) -> Result<()> {
+        let _ = request;
+        Ok(())
+    }
+
+    /// Runs after a successful response is received
+    ///
+    /// The response may be rewritten; returning an error fails the call.
+    fn after_response(
+        &self,
+        request: &Request,
+        response: &mut Response,
+        elapsed: Duration,
+    ) -> Result<()> {
+        let _ = (request, response, elapsed);
+        Ok(())
+    }
+
+    /// Runs when the request fails
+    fn on_error(&self, request: &Request, error: &SerperError, elapsed: Duration) {
+        let _ = (request, error, elapsed);
+    }
+}
+
+impl<T: Middleware + ?Sized> Middleware for Arc<T> {
+    fn before_request(&self, request: &mut Request) -> Result<()> {
+        (**self).before_request(request)
+    }
+
+    fn after_response(
+        &self,
+        request: &Request,
+        response: &mut Response,
+        elapsed: Duration,
+    ) -> Result<()> {
+        (**self).after_response(request, response, elapsed)
+    }
+
+    fn on_error(&self, request: &Request, error: &SerperError, elapsed: Duration) {
+        (**self).on_error(request, error, elapsed)
+    }
+}
+
+/// Middleware running a closure before each request
+pub(crate) struct BeforeRequest<F>(pub(crate) F);
+
+impl<F> Middleware for BeforeRequest<F>
+where
+    F: Fn(&mut Request) -> Result<()> + Send + Sync,
+{
+    fn before_request(&self, request: &mut Request) -> Result<()> {
+        (self.0)(request)
+    }
+}
+
+/// Middleware running a closure after each successful response
+pub(crate) struct AfterResponse<F>(pub(crate) F);
+
+impl<F> Middleware for AfterResponse<F>
+where
+    F: Fn(&Request, &mut Response, Duration) -> Result<()> + Send + Sync,
+{
+    fn after_response(
+        &self,
+        request: &Request,
+        response: &mut Response,
+        elapsed: Duration,
+    ) -> Result<()> {
+        (self.0)(request, response, elapsed)
+    }
+}
+
+/// Transport that runs a middleware chain around another transport
+///
+/// `before_request` hooks run in the order the middleware was added, and
+/// `after_response` and `on_error` hooks run in reverse order, so the first
+/// middleware wraps all the others.
+pub struct MiddlewareTransport {
+    inner: Box<dyn Transport>,
+    middleware: Vec<Arc<dyn Middleware>>,
+}
+
+impl MiddlewareTransport {
+    /// Creates a middleware transport around `inner` with an empty chain
+    pub fn new(inner: impl Transport + 'static) -> Self {
+        Self {
+            inner: Box::new(inner),
+            middleware: Vec::new(),
+        }
+    }
+
+    /// Appends a middleware to the chain
+    pub fn with(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Appends shared middleware to the chain
+    pub(crate) fn with_all(mut self, middleware: Vec<Arc<dyn Middleware>>) -> Self {
+        self.middleware.extend(middleware);
+        self
+    }
+
+    /// Gets the number of middleware in the chain
+    pub fn len(&self) -> usize {
+        self.middleware.len()
+    }
+
+    /// Checks if the chain is empty
+    pub fn is_empty(&self) -> bool {
+        self.middleware.is_empty()
+    }
+
+    /// Sends a request through the chain
+    async fn execute(&self, mut request: Request) -> Result<Response> {
+        for middleware in &self.middleware {
+            middleware.before_request(&mut request)?;
+        }
+
+        let started = Instant::now();
+        let result = self.inner.send(request.clone()).await;
+        let elapsed = started.elapsed();
+
+        let mut response = match result {
+            Ok(response) => response,
+            Err(error) => {
+                for middleware in self.middleware.iter().rev() {
+                    middleware.on_error(&request, &error, elapsed);
+                }
+                return Err(error);
+            }
+        };
+
+        for middleware in self.middleware.iter().rev() {
+            middleware.after_response(&request, &mut response, elapsed)?;
+        }
+        Ok(response)
+    }
+}
+
+impl Transport for MiddlewareTransport {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response>> {
+        Box::pin(self.execute(request))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// Transport echoing the request headers back as the response body
+    struct EchoHeaders;
+
+    impl Transport for EchoHeaders {
+        fn send(&self, request: Request) -> BoxFuture<'_, Result<Response>> {
+            Box::pin(async move { Response::from_json(200, &request.headers) })
+        }
+    }
+
+    /// Middleware recording the order its hooks run in
+    struct Recorder {
+        name: &'static str,
+        log: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Middleware for Recorder {
+        fn before_request(&self, request: &mut Request) -> Result<()> {
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("before {}", self.name));
+            request
+                .headers
+                .insert(self.name.to_string(), "1".to_string());
+            Ok(())
+        }
+
+        fn after_response(&self, _: &Request, _: &mut Response, _: Duration) -> Result<()> {
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("after {}", self.name));
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chain_order() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let transport = MiddlewareTransport::new(EchoHeaders)
+            .with(Recorder {
+                name: "outer",
+                log: Arc::clone(&log),
+            })
+            .with(Recorder {
+                name: "inner",
+                log: Arc::clone(&log),
+            });
+        assert_eq!(transport.len(), 2);
+
+        let response = transport
+            .send(Request::get("https://example.com"))
+            .await
+            .unwrap();
+        let headers: std::collections::HashMap<String, String> = response.json().unwrap();
+        assert!(headers.contains_key("outer") && headers.contains_key("inner"));
+        assert_eq!(
+            *log.lock().unwrap(),
+            ["before outer", "before inner", "after inner", "after outer"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_before_request_error_aborts() {
+        let transport =
+            MiddlewareTransport::new(EchoHeaders).with(BeforeRequest(|_: &mut Request| {
+                Err(SerperError::config_error("signing key missing"))
+            }));
+
+        let error = transport
+            .send(Request::get("https://example.com"))
+            .await
+            .unwrap_err();
+        assert!(matches!(error, SerperError::Config { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_after_response_rewrites() {
+        let transport = MiddlewareTransport::new(EchoHeaders).with(AfterResponse(
+            |_: &Request, response: &mut Response, _: Duration| {
+                response.body = b"{}".to_vec();
+                Ok(())
+            },
+        ));
+
+        let response = transport
+            .send(Request::get("https://example.com"))
+            .await
+            .unwrap();
+        assert_eq!(response.text(), "{}");
+    }
+}
@@ -1,9 +1,26 @@
+pub mod budget;
+pub mod circuit_breaker;
 pub mod client;
+pub mod hedge;
+pub mod key_pool;
+pub mod middleware;
+pub mod options;
+pub mod rate_limit;
+pub(crate) mod single_flight;
 /// HTTP module containing transport and client functionality
 ///
 /// This module provides HTTP transport layer abstractions and high-level
 /// client functionality for interacting with the Serper API.
 pub mod transport;
 
-pub use client::{SerperHttpClient, SerperHttpClientBuilder};
-pub use transport::{HttpTransport, HttpTransportBuilder, TransportConfig};
+pub use budget::CreditBudget;
+pub use circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, CircuitState};
+pub use client::{MAX_BATCH_SIZE, SerperHttpClient, SerperHttpClientBuilder};
+pub use hedge::{HedgeConfig, HedgeStats};
+pub use key_pool::{ApiKeyPool, KeyStats, KeyStrategy};
+pub use middleware::{Middleware, MiddlewareTransport};
+pub use options::{CancellationToken, RequestOptions};
+pub use rate_limit::RateLimiter;
+pub use transport::{
+    HttpTransport, HttpTransportBuilder, Method, Request, Response, Transport, TransportConfig,
+};
@@ -0,0 +1,227 @@
+use crate::core::{Result, SerperError};
+/// Per-request options module
+///
+/// This module provides options that apply to a single call, such as a
+/// timeout overriding the transport's default and a cancellation token.
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+
+/// Token used to cancel one or more in-flight calls
+///
+/// Clones share the same state, so a clone can be handed to the task doing
+/// the work while the original stays with the caller. Cancelling is
+/// permanent.
+///
+/// # Example
+///
+/// ```rust
+/// use serper_sdk::http::CancellationToken;
+///
+/// let token = CancellationToken::new();
+/// let worker = token.clone();
+///
+/// token.cancel();
+/// assert!(worker.is_cancelled());
+/// ```
+#[derive(Debug, Clone)]
+pub struct CancellationToken {
+    state: Arc<watch::Sender<bool>>,
+}
+
+impl CancellationToken {
+    /// Creates a token that has not been cancelled
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(watch::Sender::new(false)),
+        }
+    }
+
+    /// Cancels every call using this token or one of its clones
+    pub fn cancel(&self) {
+        self.state.send_replace(true);
+    }
+
+    /// Checks if the token has been cancelled
+    pub fn is_cancelled(&self) -> bool {
+        *self.state.borrow()
+    }
+
+    /// Waits until the token is cancelled
+    pub async fn cancelled(&self) {
+        let mut receiver = self.state.subscribe();
+        // The sender lives as long as `self`, so waiting cannot fail
+        let _ = receiver.wait_for(|cancelled| *cancelled).await;
+    }
+}
+
+impl Default for CancellationToken {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Options applying to a single call
+///
+/// `search_stream` and `paginate` take no options: dropping the stream
+/// cancels its requests, and their timeouts come from the transport
+/// configuration.
+///
+/// # Example
+///
+/// ```rust
+/// use serper_sdk::http::{CancellationToken, RequestOptions};
+/// use std::time::Duration;
+///
+/// let cancel = CancellationToken::new();
+/// let options = RequestOptions::new()
+///     .with_timeout(Duration::from_secs(2))
+///     .with_cancel(cancel.clone());
+///
+/// assert_eq!(options.timeout, Some(Duration::from_secs(2)));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RequestOptions {
+    /// Deadline of each request, including its retries and rate limiter
+    /// waits; it also overrides `TransportConfig::timeout` for each attempt
+    pub timeout: Option<Duration>,
+    /// Token that aborts the call when cancelled
+    pub cancel: Option<CancellationToken>,
+}
+
+impl RequestOptions {
+    /// Creates options that change nothing
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the deadline of each request
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the cancellation token
+    pub fn with_cancel(mut self, cancel: CancellationToken) -> Self {
+        self.cancel = Some(cancel);
+        self
+    }
+
+    /// Checks if the call has been cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+    }
+
+    /// Runs `future` until it completes, the timeout elapses or the token is cancelled
+    ///
+    /// # Arguments
+    ///
+    /// * `future` - The work to run
+    ///
+    /// # Returns
+    ///
+    /// The outcome of `future`, `SerperError::Timeout` if the timeout
+    /// elapsed first, or `SerperError::Cancelled` if the token was cancelled
+    /// first; the future is then dropped
+    pub(crate) async fn run<T>(&self, future: impl Future<Output = Result<T>>) -> Result<T> {
+        let Some(timeout) = self.timeout else {
+            return self.cancellable(future).await;
+        };
+
+        self.cancellable(async {
+            tokio::time::timeout(timeout, future)
+                .await
+                .unwrap_or(Err(SerperError::Timeout { after: timeout }))
+        })
+        .await
+    }
+
+    /// Runs `future` until it completes or the token is cancelled, without a timeout
+    ///
+    /// # Arguments
+    ///
+    /// * `future` - The work to run
+    ///
+    /// # Returns
+    ///
+    /// The outcome of `future`, or `SerperError::Cancelled` if the token was
+    /// cancelled first; the future is then dropped
+    pub(crate) async fn cancellable<T>(
+        &self,
+        future: impl Future<Output = Result<T>>,
+    ) -> Result<T> {
+        let Some(cancel) = &self.cancel else {
+            return future.await;
+        };
+        if cancel.is_cancelled() {
+            return Err(SerperError::Cancelled);
+        }
+
+        tokio::select! {
+            biased;
+            _ = cancel.cancelled() => Err(SerperError::Cancelled),
+            result = future => result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_run_without_token_completes() {
+        let options = RequestOptions::new();
+        assert_eq!(options.run(async { Ok(1) }).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_aborts_pending_work() {
+        let cancel = CancellationToken::new();
+        let options = RequestOptions::new().with_cancel(cancel.clone());
+
+        let canceller = async {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            cancel.cancel();
+        };
+        let work = options.run(async {
+            tokio::time::sleep(Duration::from_secs(10)).await;
+            Ok(())
+        });
+
+        let (_, result) = tokio::join!(canceller, work);
+        assert!(result.unwrap_err().is_cancelled());
+        assert!(options.is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn test_timeout_bounds_the_whole_call() {
+        let options = RequestOptions::new().with_timeout(Duration::from_millis(20));
+
+        let result = options
+            .run(async {
+                // Several quick steps, e.g. retries, that together overrun
+                for _ in 0..10 {
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                }
+                Ok(())
+            })
+            .await;
+        assert!(result.unwrap_err().is_timeout());
+
+        assert_eq!(options.run(async { Ok(1) }).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_token_skips_work() {
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+        let options = RequestOptions::new().with_cancel(cancel);
+
+        let result: Result<()> = options.run(async { panic!("work must not start") }).await;
+        assert!(matches!(result, Err(SerperError::Cancelled)));
+    }
+}
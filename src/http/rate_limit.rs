@@ -0,0 +1,191 @@
+use crate::core::{Result, SerperError};
+/// Client-side rate limiting module
+///
+/// This module provides a token bucket that spaces out requests so that a
+/// plan's requests-per-second cap is respected across every client sharing it.
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Token bucket rate limiter
+///
+/// The bucket holds up to `burst` tokens and refills at `requests_per_second`.
+/// Each request takes one token, waiting for it if the bucket is empty.
+/// Share one limiter between clients and services with `Arc` so they draw
+/// from the same budget.
+///
+/// # Example
+///
+/// ```rust
+/// use serper_sdk::http::RateLimiter;
+/// use serper_sdk::search::service::SearchServiceBuilder;
+/// use std::sync::Arc;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let limiter = Arc::new(RateLimiter::new(5.0, 10)?);
+///
+///     let _jobs = SearchServiceBuilder::new()
+///         .api_key("demo-key-for-docs")
+///         .rate_limiter(Arc::clone(&limiter))
+///         .build()?;
+///     let _alerts = SearchServiceBuilder::new()
+///         .api_key("demo-key-for-docs")
+///         .rate_limiter(limiter)
+///         .build()?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct RateLimiter {
+    requests_per_second: f64,
+    burst: u32,
+    bucket: Mutex<Bucket>,
+}
+
+/// Mutable state of the token bucket
+#[derive(Debug)]
+struct Bucket {
+    /// Available tokens; negative when requests are queued for future tokens
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    /// Creates a new rate limiter
+    ///
+    /// # Arguments
+    ///
+    /// * `requests_per_second` - Sustained request rate
+    /// * `burst` - Maximum number of requests allowed back to back
+    ///
+    /// # Returns
+    ///
+    /// Result containing the rate limiter or a configuration error
+    pub fn new(requests_per_second: f64, burst: u32) -> Result<Self> {
+        if !requests_per_second.is_finite() || requests_per_second <= 0.0 {
+            return Err(SerperError::config_error(
+                "Rate limit must be a positive number of requests per second",
+            ));
+        }
+        if burst == 0 {
+            return Err(SerperError::config_error(
+                "Rate limit burst must be at least 1",
+            ));
+        }
+
+        Ok(Self {
+            requests_per_second,
+            burst,
+            bucket: Mutex::new(Bucket {
+                tokens: f64::from(burst),
+                last_refill: Instant::now(),
+            }),
+        })
+    }
+
+    /// Creates a rate limiter allowing `requests_per_second` with an equal burst
+    pub fn per_second(requests_per_second: u32) -> Result<Self> {
+        Self::new(f64::from(requests_per_second), requests_per_second)
+    }
+
+    /// Gets the sustained request rate
+    pub fn requests_per_second(&self) -> f64 {
+        self.requests_per_second
+    }
+
+    /// Gets the burst size
+    pub fn burst(&self) -> u32 {
+        self.burst
+    }
+
+    /// Waits until a request may be sent
+    ///
+    /// Waiting callers are served in the order they called `acquire`.
+    pub async fn acquire(&self) {
+        let wait = self.reserve();
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Takes a token if one is available without waiting
+    ///
+    /// # Returns
+    ///
+    /// True if the request may be sent now
+    pub fn try_acquire(&self) -> bool {
+        let mut bucket = self.lock();
+        self.refill(&mut bucket);
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Takes a token, returning how long to wait until it is available
+    fn reserve(&self) -> Duration {
+        let mut bucket = self.lock();
+        self.refill(&mut bucket);
+        bucket.tokens -= 1.0;
+
+        if bucket.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-bucket.tokens / self.requests_per_second)
+        }
+    }
+
+    /// Adds the tokens accumulated since the last refill
+    fn refill(&self, bucket: &mut Bucket) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens =
+            (bucket.tokens + elapsed * self.requests_per_second).min(f64::from(self.burst));
+        bucket.last_refill = now;
+    }
+
+    /// Locks the bucket, recovering from a poisoned lock
+    fn lock(&self) -> std::sync::MutexGuard<'_, Bucket> {
+        self.bucket
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_limiter_validation() {
+        assert!(RateLimiter::new(0.0, 1).is_err());
+        assert!(RateLimiter::new(f64::NAN, 1).is_err());
+        assert!(RateLimiter::new(1.0, 0).is_err());
+
+        let limiter = RateLimiter::per_second(5).unwrap();
+        assert_eq!(limiter.requests_per_second(), 5.0);
+        assert_eq!(limiter.burst(), 5);
+    }
+
+    #[test]
+    fn test_burst_then_empty() {
+        let limiter = RateLimiter::new(1.0, 2).unwrap();
+        assert!(limiter.try_acquire());
+        assert!(limiter.try_acquire());
+        assert!(!limiter.try_acquire());
+    }
+
+    #[tokio::test]
+    async fn test_acquire_spaces_requests() {
+        let limiter = RateLimiter::new(50.0, 1).unwrap();
+        let started = Instant::now();
+
+        for _ in 0..4 {
+            limiter.acquire().await;
+        }
+
+        // One token is available immediately, the other three take 20ms each
+        assert!(started.elapsed() >= Duration::from_millis(55));
+    }
+}
@@ -0,0 +1,189 @@
+use crate::core::{Result, SerperError};
+/// Request coalescing module
+///
+/// This module lets concurrent callers asking for the same key share one
+/// in-flight request instead of each sending their own.
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use tokio::sync::watch;
+
+/// Outcome shared with the callers waiting on a request
+type Shared<T> = std::result::Result<T, Arc<SerperError>>;
+
+/// Coalesces concurrent calls with equal keys into a single call
+///
+/// The first caller for a key (the leader) runs the request; callers that
+/// arrive while it is in flight wait for its outcome. The leader gets the
+/// original result and waiters get copies, with errors copied by
+/// [`SerperError::duplicate`]. If the leader is cancelled, one of the waiters
+/// takes over.
+#[derive(Debug)]
+pub(crate) struct SingleFlight<T> {
+    in_flight: Mutex<HashMap<String, watch::Receiver<Option<Shared<T>>>>>,
+}
+
+/// Role of a caller for one key
+enum Role<T> {
+    Leader(watch::Sender<Option<Shared<T>>>),
+    Waiter(watch::Receiver<Option<Shared<T>>>),
+}
+
+impl<T: Clone> SingleFlight<T> {
+    /// Creates an empty coalescer
+    pub(crate) fn new() -> Self {
+        Self {
+            in_flight: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Runs `call` unless an equal call is in flight, then shares its outcome
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Key identifying equal calls
+    /// * `call` - Starts the call; it runs at most once per caller
+    ///
+    /// # Returns
+    ///
+    /// The outcome of this caller's call or of the in-flight one
+    pub(crate) async fn run<F, Fut>(&self, key: String, call: F) -> Result<T>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        loop {
+            let role = {
+                let mut in_flight = self.lock();
+                match in_flight.get(&key) {
+                    Some(receiver) => Role::Waiter(receiver.clone()),
+                    None => {
+                        let (sender, receiver) = watch::channel(None);
+                        in_flight.insert(key.clone(), receiver);
+                        Role::Leader(sender)
+                    }
+                }
+            };
+
+            match role {
+                Role::Leader(sender) => {
+                    // Dropped before `sender`, so a cancelled leader is
+                    // unregistered before its waiters wake up
+                    let _registration = Registration {
+                        flights: self,
+                        key: &key,
+                    };
+
+                    let result = call().await;
+                    let shared = match &result {
+                        Ok(value) => Ok(value.clone()),
+                        Err(error) => Err(Arc::new(error.duplicate())),
+                    };
+                    sender.send_replace(Some(shared));
+                    return result;
+                }
+                Role::Waiter(mut receiver) => {
+                    if let Ok(outcome) = receiver.wait_for(Option::is_some).await {
+                        return match outcome.as_ref() {
+                            Some(Ok(value)) => Ok(value.clone()),
+                            Some(Err(error)) => Err(error.duplicate()),
+                            None => unreachable!("waited for an outcome"),
+                        };
+                    }
+                    // The leader was cancelled; try to lead a new call
+                }
+            }
+        }
+    }
+}
+
+impl<T> SingleFlight<T> {
+    /// Locks the in-flight map, recovering from a poisoned lock
+    fn lock(
+        &self,
+    ) -> std::sync::MutexGuard<'_, HashMap<String, watch::Receiver<Option<Shared<T>>>>> {
+        self.in_flight
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Removes a leader's key when its call finishes or is cancelled
+struct Registration<'a, T> {
+    flights: &'a SingleFlight<T>,
+    key: &'a str,
+}
+
+impl<T> Drop for Registration<'_, T> {
+    fn drop(&mut self) {
+        self.flights.lock().remove(self.key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_concurrent_calls_share_one_request() {
+        let flights = SingleFlight::new();
+        let calls = AtomicUsize::new(0);
+        let call = || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            Ok(42)
+        };
+
+        let results =
+            futures::future::join_all((0..5).map(|_| flights.run("key".to_string(), call))).await;
+
+        assert!(results.iter().all(|result| matches!(result, Ok(42))));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // Finished calls are not reused
+        flights.run("key".to_string(), call).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_errors_are_shared() {
+        let flights = SingleFlight::<u32>::new();
+        let call = || async {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            Err(SerperError::Http {
+                status: 502,
+                body: None,
+                request_id: Some("req-1".to_string()),
+            })
+        };
+
+        let (leader, waiter) = tokio::join!(
+            flights.run("key".to_string(), call),
+            flights.run("key".to_string(), call)
+        );
+        assert_eq!(leader.unwrap_err().status_code(), Some(502));
+        assert_eq!(waiter.unwrap_err().status_code(), Some(502));
+    }
+
+    #[tokio::test]
+    async fn test_waiter_takes_over_from_cancelled_leader() {
+        let flights = SingleFlight::new();
+        let calls = AtomicUsize::new(0);
+        let call = || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            Ok(7)
+        };
+
+        let leader = flights.run("key".to_string(), call);
+        let waiter = flights.run("key".to_string(), call);
+        let cancelled = tokio::time::timeout(Duration::from_millis(5), leader);
+
+        let (cancelled, waited) = tokio::join!(cancelled, waiter);
+        assert!(cancelled.is_err());
+        assert_eq!(waited.unwrap(), 7);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+}
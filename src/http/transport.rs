@@ -3,10 +3,267 @@ use crate::core::{Result, SerperError, types::ApiKey};
 ///
 /// This module provides a clean abstraction over HTTP operations,
 /// making it easy to swap out underlying HTTP clients or add middleware.
-use reqwest::{Client as ReqwestClient, Method, Response};
-use serde::Serialize;
+use crate::http::circuit_breaker::{CircuitBreaker, CircuitBreakerConfig};
+use crate::http::rate_limit::RateLimiter;
+use crate::utils::retry::{RetryConfig, RetryEvent};
+use futures::future::BoxFuture;
+use reqwest::{Client as ReqwestClient, StatusCode, header::RETRY_AFTER};
+use serde::{Serialize, de::DeserializeOwned};
 use std::collections::HashMap;
-use std::time::Duration;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tokio::time::sleep;
+
+pub use reqwest::Method;
+
+/// Response header carrying the request identifier
+const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Header carrying the Serper API key
+pub(crate) const API_KEY_HEADER: &str = "X-API-KEY";
+
+/// A transport that sends requests to the Serper API
+///
+/// [`HttpTransport`] is the default implementation. Implement this trait to
+/// plug in another HTTP stack, or to return canned responses in unit tests
+/// without a network connection.
+///
+/// Implementations may return error statuses either as a [`Response`] or as
+/// an error; callers map error statuses with [`Response::error_for_status`].
+///
+/// # Example
+///
+/// ```rust
+/// use futures::future::BoxFuture;
+/// use serper_sdk::core::Result;
+/// use serper_sdk::http::{Request, Response, Transport};
+/// use serper_sdk::search::service::SearchServiceBuilder;
+///
+/// struct CannedTransport;
+///
+/// impl Transport for CannedTransport {
+///     fn send(&self, _request: Request) -> BoxFuture<'_, Result<Response>> {
+///         Box::pin(async { Ok(Response::new(200, r#"{"organic": []}"#)) })
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let service = SearchServiceBuilder::new()
+///         .api_key("demo-key-for-docs")
+///         .transport(CannedTransport)
+///         .build()?;
+///
+///     let response = service.search_simple("rust").await?;
+///     assert!(response.organic_results().is_empty());
+///     Ok(())
+/// }
+/// ```
+pub trait Transport: Send + Sync {
+    /// Sends a request and returns its response
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send
+    ///
+    /// # Returns
+    ///
+    /// A future resolving to the response or an error
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response>>;
+}
+
+impl<T: Transport + ?Sized> Transport for Box<T> {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response>> {
+        (**self).send(request)
+    }
+}
+
+impl<T: Transport + ?Sized> Transport for Arc<T> {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response>> {
+        (**self).send(request)
+    }
+}
+
+/// An HTTP request independent of the underlying HTTP client
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    /// HTTP method
+    pub method: Method,
+    /// Full request URL
+    pub url: String,
+    /// Request headers
+    pub headers: HashMap<String, String>,
+    /// Request body, if any
+    pub body: Option<Vec<u8>>,
+    /// Timeout overriding the transport's default, if any
+    pub timeout: Option<Duration>,
+}
+
+impl Request {
+    /// Creates a request without headers or body
+    pub fn new(method: Method, url: impl Into<String>) -> Self {
+        Self {
+            method,
+            url: url.into(),
+            headers: HashMap::new(),
+            body: None,
+            timeout: None,
+        }
+    }
+
+    /// Creates a GET request
+    pub fn get(url: impl Into<String>) -> Self {
+        Self::new(Method::GET, url)
+    }
+
+    /// Creates a POST request with a JSON body
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The request URL
+    /// * `body` - The request body that can be serialized to JSON
+    ///
+    /// # Returns
+    ///
+    /// Result containing the request or a serialization error
+    pub fn post_json<T: Serialize + ?Sized>(url: impl Into<String>, body: &T) -> Result<Self> {
+        let mut request =
+            Self::new(Method::POST, url).with_header("Content-Type", "application/json");
+        request.body = Some(serde_json::to_vec(body)?);
+        Ok(request)
+    }
+
+    /// Adds a header, replacing any existing value
+    pub fn with_header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(key.into(), value.into());
+        self
+    }
+
+    /// Sets a timeout overriding the transport's default
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Gets a header value by case-insensitive name
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+
+    /// Parses the request body as JSON
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T> {
+        let body = self.body.as_deref().unwrap_or_default();
+        Ok(serde_json::from_slice(body)?)
+    }
+}
+
+/// An HTTP response independent of the underlying HTTP client
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    /// HTTP status code
+    pub status: u16,
+    /// Response headers with lower-case names
+    pub headers: HashMap<String, String>,
+    /// Response body
+    pub body: Vec<u8>,
+}
+
+impl Response {
+    /// Creates a response with the given status and body
+    pub fn new(status: u16, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status,
+            headers: HashMap::new(),
+            body: body.into(),
+        }
+    }
+
+    /// Creates a response with a JSON body
+    pub fn from_json<T: Serialize + ?Sized>(status: u16, body: &T) -> Result<Self> {
+        Ok(Self::new(status, serde_json::to_vec(body)?)
+            .with_header("content-type", "application/json"))
+    }
+
+    /// Adds a header, replacing any existing value
+    pub fn with_header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers
+            .insert(key.into().to_ascii_lowercase(), value.into());
+        self
+    }
+
+    /// Gets a header value by case-insensitive name
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+
+    /// Checks if the status is in the 2xx range
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Gets the body as text, replacing invalid UTF-8
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    /// Parses the body as JSON
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T> {
+        Ok(serde_json::from_slice(&self.body)?)
+    }
+
+    /// Converts an error status into the matching error
+    ///
+    /// 401/403 become `InvalidApiKey`, 429 becomes `RateLimited`, and other
+    /// non-2xx statuses become `Http` with the response body and request id.
+    pub fn error_for_status(self) -> Result<Self> {
+        if self.is_success() {
+            return Ok(self);
+        }
+
+        Err(match StatusCode::from_u16(self.status) {
+            Ok(StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN) => SerperError::InvalidApiKey,
+            Ok(StatusCode::TOO_MANY_REQUESTS) => SerperError::RateLimited {
+                retry_after: self
+                    .header(RETRY_AFTER.as_str())
+                    .and_then(|value| parse_retry_after(value, SystemTime::now())),
+            },
+            _ => SerperError::Http {
+                status: self.status,
+                request_id: self.header(REQUEST_ID_HEADER).map(str::to_string),
+                body: Some(self.text()).filter(|body| !body.trim().is_empty()),
+            },
+        })
+    }
+
+    /// Reads a reqwest response into memory
+    async fn from_reqwest(response: reqwest::Response) -> Result<Self> {
+        let status = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| {
+                let value = value.to_str().ok()?;
+                Some((name.as_str().to_string(), value.to_string()))
+            })
+            .collect();
+        let body = response.bytes().await.map_err(SerperError::Request)?;
+
+        Ok(Self {
+            status,
+            headers,
+            body: body.to_vec(),
+        })
+    }
+}
+
+/// Looks up a header by case-insensitive name
+fn find_header<'a>(headers: &'a HashMap<String, String>, name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
 
 /// HTTP transport configuration
 #[derive(Debug, Clone)]
@@ -17,6 +274,12 @@ pub struct TransportConfig {
     pub default_headers: HashMap<String, String>,
     /// User agent string
     pub user_agent: String,
+    /// Retry policy for transient failures (disabled when `None`)
+    pub retry: Option<RetryConfig>,
+    /// Rate limiter applied to every request attempt, possibly shared
+    pub rate_limiter: Option<Arc<RateLimiter>>,
+    /// Circuit breaker settings (disabled when `None`)
+    pub circuit_breaker: Option<CircuitBreakerConfig>,
 }
 
 impl TransportConfig {
@@ -29,6 +292,9 @@ impl TransportConfig {
             timeout: Duration::from_secs(30),
             default_headers,
             user_agent: format!("serper-sdk/{}", env!("CARGO_PKG_VERSION")),
+            retry: None,
+            rate_limiter: None,
+            circuit_breaker: None,
         }
     }
 
@@ -49,6 +315,24 @@ impl TransportConfig {
         self.user_agent = user_agent;
         self
     }
+
+    /// Enables retries of transient failures (connect errors, timeouts, 429 and 5xx)
+    pub fn with_retry(mut self, retry: RetryConfig) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    /// Sets a rate limiter; pass clones of one `Arc` to share a budget
+    pub fn with_rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Enables a circuit breaker that fails fast after repeated transient failures
+    pub fn with_circuit_breaker(mut self, circuit_breaker: CircuitBreakerConfig) -> Self {
+        self.circuit_breaker = Some(circuit_breaker);
+        self
+    }
 }
 
 impl Default for TransportConfig {
@@ -61,10 +345,13 @@ impl Default for TransportConfig {
 ///
 /// This struct handles all HTTP operations with automatic retry,
 /// error handling, and request/response logging.
-#[derive(Debug)]
+///
+/// Clones share the underlying connection pool.
+#[derive(Debug, Clone)]
 pub struct HttpTransport {
     client: ReqwestClient,
     config: TransportConfig,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
 }
 
 impl HttpTransport {
@@ -81,7 +368,16 @@ impl HttpTransport {
             .build()
             .map_err(SerperError::Request)?;
 
-        Ok(Self { client, config })
+        let circuit_breaker = config
+            .circuit_breaker
+            .clone()
+            .map(|breaker| Arc::new(CircuitBreaker::new(breaker)));
+
+        Ok(Self {
+            client,
+            config,
+            circuit_breaker,
+        })
     }
 
     /// Makes a POST request with JSON body
@@ -101,36 +397,8 @@ impl HttpTransport {
         api_key: &ApiKey,
         body: &T,
     ) -> Result<Response> {
-        let mut request = self
-            .client
-            .request(Method::POST, url)
-            .header("X-API-KEY", api_key.as_str());
-
-        // Add default headers (except Content-Type since .json() will set it)
-        for (key, value) in &self.config.default_headers {
-            if key != "Content-Type" {
-                request = request.header(key, value);
-            }
-        }
-
-        // Set JSON body (this will automatically set Content-Type: application/json)
-        request = request.json(body);
-
-        let response = request.send().await.map_err(SerperError::Request)?;
-
-        // Check for HTTP error status codes
-        if !response.status().is_success() {
-            return Err(SerperError::api_error(format!(
-                "HTTP {} - {}",
-                response.status(),
-                response
-                    .status()
-                    .canonical_reason()
-                    .unwrap_or("Unknown error")
-            )));
-        }
-
-        Ok(response)
+        let request = Request::post_json(url, body)?.with_header(API_KEY_HEADER, api_key.as_str());
+        self.execute(request).await
     }
 
     /// Makes a GET request
@@ -144,32 +412,51 @@ impl HttpTransport {
     ///
     /// Result containing the HTTP response or an error
     pub async fn get(&self, url: &str, api_key: &ApiKey) -> Result<Response> {
-        let mut request = self
-            .client
-            .request(Method::GET, url)
-            .header("X-API-KEY", api_key.as_str());
+        let request = Request::get(url).with_header(API_KEY_HEADER, api_key.as_str());
+        self.execute(request).await
+    }
+
+    /// Sends a request, retrying transient failures according to the retry policy
+    async fn execute(&self, request: Request) -> Result<Response> {
+        execute_with(&self.config, self.circuit_breaker.as_deref(), || {
+            self.send_once(&request)
+        })
+        .await
+    }
+
+    /// Sends a single request attempt
+    async fn send_once(&self, request: &Request) -> Result<Response> {
+        match self.to_reqwest(request).send().await {
+            Ok(response) => Response::from_reqwest(response)
+                .await
+                .and_then(Response::error_for_status),
+            Err(error) => Err(SerperError::Request(error)),
+        }
+    }
+
+    /// Builds a reqwest request, adding the default headers the request does not set
+    fn to_reqwest(&self, request: &Request) -> reqwest::RequestBuilder {
+        let mut builder = self.client.request(request.method.clone(), &request.url);
 
-        // Add default headers (except Content-Type for GET)
         for (key, value) in &self.config.default_headers {
-            if key != "Content-Type" {
-                request = request.header(key, value);
+            // Content-Type only applies to requests with a body
+            let skip = request.header(key).is_some()
+                || (request.body.is_none() && key.eq_ignore_ascii_case("Content-Type"));
+            if !skip {
+                builder = builder.header(key, value);
             }
         }
-
-        let response = request.send().await.map_err(SerperError::Request)?;
-
-        if !response.status().is_success() {
-            return Err(SerperError::api_error(format!(
-                "HTTP {} - {}",
-                response.status(),
-                response
-                    .status()
-                    .canonical_reason()
-                    .unwrap_or("Unknown error")
-            )));
+        for (key, value) in &request.headers {
+            builder = builder.header(key, value);
+        }
+        if let Some(body) = &request.body {
+            builder = builder.body(body.clone());
+        }
+        if let Some(timeout) = request.timeout {
+            builder = builder.timeout(timeout);
         }
 
-        Ok(response)
+        builder
     }
 
     /// Parses a response as JSON
@@ -183,15 +470,165 @@ impl HttpTransport {
     /// Result containing the parsed JSON or an error
     pub async fn parse_json<T>(&self, response: Response) -> Result<T>
     where
-        T: serde::de::DeserializeOwned,
+        T: DeserializeOwned,
     {
-        response.json().await.map_err(SerperError::Request)
+        response.json()
     }
 
     /// Gets the current transport configuration
     pub fn config(&self) -> &TransportConfig {
         &self.config
     }
+
+    /// Gets the circuit breaker shared by this transport and its clones, if enabled
+    pub fn circuit_breaker(&self) -> Option<&CircuitBreaker> {
+        self.circuit_breaker.as_deref()
+    }
+
+    /// Gets a shared handle to the circuit breaker, if enabled
+    pub(crate) fn circuit_breaker_handle(&self) -> Option<Arc<CircuitBreaker>> {
+        self.circuit_breaker.clone()
+    }
+}
+
+impl Transport for HttpTransport {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response>> {
+        Box::pin(self.execute(request))
+    }
+}
+
+/// A custom transport with the policies of a [`TransportConfig`]
+///
+/// The client builders wrap custom transports in one, so that the timeout,
+/// default headers, retry policy, rate limiter and circuit breaker apply to
+/// them as they do to [`HttpTransport`]. The user agent is left to the
+/// custom transport.
+pub(crate) struct ConfiguredTransport {
+    inner: Box<dyn Transport>,
+    config: TransportConfig,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+}
+
+impl ConfiguredTransport {
+    /// Wraps a transport in the policies of a configuration
+    pub(crate) fn new(inner: Box<dyn Transport>, config: TransportConfig) -> Self {
+        let circuit_breaker = config
+            .circuit_breaker
+            .clone()
+            .map(|breaker| Arc::new(CircuitBreaker::new(breaker)));
+
+        Self {
+            inner,
+            config,
+            circuit_breaker,
+        }
+    }
+
+    /// Gets a shared handle to the circuit breaker, if enabled
+    pub(crate) fn circuit_breaker_handle(&self) -> Option<Arc<CircuitBreaker>> {
+        self.circuit_breaker.clone()
+    }
+
+    /// Adds the default headers the request does not set, then sends it
+    async fn execute(&self, mut request: Request) -> Result<Response> {
+        for (key, value) in &self.config.default_headers {
+            // Content-Type only applies to requests with a body
+            let skip = request.header(key).is_some()
+                || (request.body.is_none() && key.eq_ignore_ascii_case("Content-Type"));
+            if !skip {
+                request.headers.insert(key.clone(), value.clone());
+            }
+        }
+
+        execute_with(&self.config, self.circuit_breaker.as_deref(), || {
+            self.send_once(&request)
+        })
+        .await
+    }
+
+    /// Sends a single request attempt, failing it once its timeout elapses
+    async fn send_once(&self, request: &Request) -> Result<Response> {
+        let timeout = request.timeout.unwrap_or(self.config.timeout);
+        match tokio::time::timeout(timeout, self.inner.send(request.clone())).await {
+            Ok(result) => result.and_then(Response::error_for_status),
+            Err(_) => Err(SerperError::Timeout { after: timeout }),
+        }
+    }
+}
+
+impl Transport for ConfiguredTransport {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response>> {
+        Box::pin(self.execute(request))
+    }
+}
+
+/// Runs request attempts, retrying transient failures according to the retry policy
+///
+/// Each attempt is first checked against the circuit breaker and waits for
+/// the rate limiter, if any, and its outcome is recorded in the breaker.
+/// Each retry is reported to the policy's `on_retry` callback before waiting.
+///
+/// # Arguments
+///
+/// * `config` - Configuration holding the retry policy and rate limiter
+/// * `circuit_breaker` - Circuit breaker guarding the attempts, if any
+/// * `send_once` - Function sending one attempt
+async fn execute_with<F, Fut>(
+    config: &TransportConfig,
+    circuit_breaker: Option<&CircuitBreaker>,
+    send_once: F,
+) -> Result<Response>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<Response>>,
+{
+    let retry = config.retry.as_ref();
+    let max_attempts = retry.map_or(1, |retry| retry.max_attempts.max(1));
+    let mut attempt = 1;
+
+    loop {
+        if let Some(breaker) = circuit_breaker {
+            breaker.check()?;
+        }
+        if let Some(rate_limiter) = &config.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+
+        let result = send_once().await;
+        if let Some(breaker) = circuit_breaker {
+            breaker.record(result.as_ref().map(|_| ()));
+        }
+        let error = match result {
+            Ok(response) => return Ok(response),
+            Err(error) => error,
+        };
+
+        match retry {
+            Some(retry) if error.is_retryable() && attempt < max_attempts => {
+                let delay = retry.delay_after(attempt, &error);
+                retry.notify(&RetryEvent {
+                    attempt,
+                    max_attempts,
+                    delay,
+                    error: &error,
+                });
+                sleep(delay).await;
+                attempt += 1;
+            }
+            _ => return Err(error),
+        }
+    }
+}
+
+/// Parses a `Retry-After` header given in seconds or as an HTTP date
+fn parse_retry_after(value: &str, now: SystemTime) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(date.duration_since(now).unwrap_or(Duration::ZERO))
 }
 
 impl Default for HttpTransport {
@@ -231,6 +668,24 @@ impl HttpTransportBuilder {
         self
     }
 
+    /// Enables retries of transient failures
+    pub fn retry(mut self, retry: RetryConfig) -> Self {
+        self.config = self.config.with_retry(retry);
+        self
+    }
+
+    /// Sets a shared rate limiter
+    pub fn rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.config = self.config.with_rate_limiter(rate_limiter);
+        self
+    }
+
+    /// Enables a circuit breaker
+    pub fn circuit_breaker(mut self, circuit_breaker: CircuitBreakerConfig) -> Self {
+        self.config = self.config.with_circuit_breaker(circuit_breaker);
+        self
+    }
+
     /// Builds the HTTP transport
     pub fn build(self) -> Result<HttpTransport> {
         HttpTransport::with_config(self.config)
@@ -279,6 +734,92 @@ mod tests {
         let transport = builder.build().unwrap();
         assert_eq!(transport.config().timeout, Duration::from_secs(45));
         assert_eq!(transport.config().user_agent, "test-agent");
+        assert!(transport.config().retry.is_none());
+
+        let transport = HttpTransportBuilder::new()
+            .retry(RetryConfig::new().with_max_attempts(4))
+            .build()
+            .unwrap();
+        assert_eq!(transport.config().retry.as_ref().unwrap().max_attempts, 4);
+    }
+
+    #[test]
+    fn test_request_post_json() {
+        let request = Request::post_json("https://example.com/search", &vec!["rust"])
+            .unwrap()
+            .with_header(API_KEY_HEADER, "key");
+
+        assert_eq!(request.method, Method::POST);
+        assert_eq!(request.header("content-type"), Some("application/json"));
+        assert_eq!(request.header("x-api-key"), Some("key"));
+        assert_eq!(request.json::<Vec<String>>().unwrap(), vec!["rust"]);
+        assert!(Request::get("https://example.com").body.is_none());
+    }
+
+    #[test]
+    fn test_request_timeout_overrides_default() {
+        let transport = HttpTransport::new().unwrap();
+
+        let request = Request::get("https://example.com").with_timeout(Duration::from_secs(2));
+        let built = transport.to_reqwest(&request).build().unwrap();
+        assert_eq!(built.timeout(), Some(&Duration::from_secs(2)));
+
+        let built = transport
+            .to_reqwest(&Request::get("https://example.com"))
+            .build()
+            .unwrap();
+        assert_eq!(built.timeout(), None);
+    }
+
+    #[test]
+    fn test_response_error_for_status() {
+        assert!(Response::new(200, "{}").error_for_status().is_ok());
+        assert!(matches!(
+            Response::new(403, "").error_for_status(),
+            Err(SerperError::InvalidApiKey)
+        ));
+
+        let error = Response::new(429, "")
+            .with_header("Retry-After", "5")
+            .error_for_status()
+            .unwrap_err();
+        assert_eq!(error.retry_after(), Some(Duration::from_secs(5)));
+
+        let error = Response::new(500, r#"{"message": "boom"}"#)
+            .with_header("X-Request-Id", "req-1")
+            .error_for_status()
+            .unwrap_err();
+        match error {
+            SerperError::Http {
+                status,
+                body,
+                request_id,
+            } => {
+                assert_eq!(status, 500);
+                assert_eq!(body.as_deref(), Some(r#"{"message": "boom"}"#));
+                assert_eq!(request_id.as_deref(), Some("req-1"));
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_445_412_480);
+
+        assert_eq!(
+            parse_retry_after("120", now),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:30 GMT", now),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:27:00 GMT", now),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon", now), None);
     }
 
     #[test]
@@ -136,8 +136,8 @@ pub mod utils;
 pub use config::{SdkConfig, SdkConfigBuilder};
 pub use core::{Result, SerperError};
 pub use search::{
-    AnswerBox, KnowledgeGraph, OrganicResult, SearchMetadata, SearchQuery, SearchQueryBuilder,
-    SearchResponse, SearchService,
+    AnswerBox, BatchReport, KnowledgeGraph, OrganicResult, QueryTemplate, ResultSet,
+    SearchMetadata, SearchQuery, SearchQueryBuilder, SearchResponse, SearchService,
 };
 
 // Legacy compatibility - re-export the main client for backward compatibility
@@ -0,0 +1,387 @@
+use crate::search::{query::SearchQuery, response::SearchResponse};
+/// Response cache module
+///
+/// This module provides an in-memory cache of search responses with a
+/// time-to-live and least-recently-used eviction, so repeated queries do not
+/// spend credits.
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Default time a cached response stays fresh
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(300);
+
+/// Default maximum number of cached responses
+pub const DEFAULT_CACHE_CAPACITY: usize = 1000;
+
+/// Response cache configuration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheConfig {
+    /// How long a response stays fresh after it is stored
+    pub ttl: Duration,
+    /// Maximum number of responses kept; the least recently used is evicted
+    pub capacity: usize,
+}
+
+impl CacheConfig {
+    /// Creates a configuration with a 5 minute TTL and room for 1000 responses
+    pub fn new() -> Self {
+        Self {
+            ttl: DEFAULT_CACHE_TTL,
+            capacity: DEFAULT_CACHE_CAPACITY,
+        }
+    }
+
+    /// Sets how long a response stays fresh
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Sets the maximum number of cached responses
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Cache usage counters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Lookups answered from the cache
+    pub hits: u64,
+    /// Lookups that found no fresh response
+    pub misses: u64,
+    /// Responses dropped to stay within capacity
+    pub evictions: u64,
+    /// Responses currently stored, including expired ones not yet removed
+    pub entries: usize,
+}
+
+impl CacheStats {
+    /// Gets the fraction of lookups answered from the cache
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+/// Builds the cache key of a query
+///
+/// The key is the query serialized as JSON after normalizing the search
+/// text (trimmed, inner whitespace collapsed) and treating page 1 as no
+/// page, so trivially different queries share an entry. Case is kept:
+/// Google operators such as `OR` and `AROUND(n)` only work in upper case.
+///
+/// # Arguments
+///
+/// * `query` - The search query
+///
+/// # Returns
+///
+/// The cache key
+pub fn cache_key(query: &SearchQuery) -> String {
+    let mut normalized = query.clone();
+    normalized.q = query.q.split_whitespace().collect::<Vec<_>>().join(" ");
+    if normalized.page == Some(1) {
+        normalized.page = None;
+    }
+
+    serde_json::to_string(&normalized).expect("search queries serialize to JSON")
+}
+
+/// In-memory response cache with TTL expiry and LRU eviction
+///
+/// The cache is safe to share between tasks; clients hold it behind their
+/// shared state so clones of a service use the same cache.
+///
+/// # Example
+///
+/// ```rust
+/// use serper_sdk::search::{CacheConfig, SearchQuery};
+/// use serper_sdk::search::service::SearchServiceBuilder;
+/// use std::time::Duration;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let service = SearchServiceBuilder::new()
+///         .api_key("demo-key-for-docs")
+///         .cache(CacheConfig::new().with_ttl(Duration::from_secs(600)).with_capacity(500))
+///         .build()?;
+///
+///     let cache = service.cache().unwrap();
+///     assert!(cache.get(&SearchQuery::new("rust".to_string())?).is_none());
+///     assert_eq!(cache.stats().misses, 1);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ResponseCache {
+    config: CacheConfig,
+    inner: Mutex<CacheInner>,
+}
+
+/// Mutable state of a response cache
+#[derive(Debug, Default)]
+struct CacheInner {
+    entries: HashMap<String, CacheEntry>,
+    /// Monotonic counter recording the order of use
+    clock: u64,
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+}
+
+/// A cached response with its bookkeeping
+#[derive(Debug)]
+struct CacheEntry {
+    response: SearchResponse,
+    stored_at: Instant,
+    last_used: u64,
+}
+
+impl ResponseCache {
+    /// Creates an empty cache
+    pub fn new(config: CacheConfig) -> Self {
+        Self {
+            config,
+            inner: Mutex::new(CacheInner::default()),
+        }
+    }
+
+    /// Gets the cache configuration
+    pub fn config(&self) -> &CacheConfig {
+        &self.config
+    }
+
+    /// Looks up a fresh response for a query
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The search query
+    ///
+    /// # Returns
+    ///
+    /// A copy of the cached response, or `None` if there is none or it expired
+    pub fn get(&self, query: &SearchQuery) -> Option<SearchResponse> {
+        self.get_with_max_age(query, self.config.ttl)
+    }
+
+    /// Looks up a response stored less than `max_age` ago
+    ///
+    /// `max_age` overrides the TTL for this lookup: a shorter age demands
+    /// fresher data, and a longer one accepts entries past the TTL that have
+    /// not been removed yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The search query
+    /// * `max_age` - Maximum age of an acceptable response
+    ///
+    /// # Returns
+    ///
+    /// A copy of the cached response, or `None` if there is no acceptable entry
+    pub fn get_with_max_age(
+        &self,
+        query: &SearchQuery,
+        max_age: Duration,
+    ) -> Option<SearchResponse> {
+        let key = cache_key(query);
+        let mut inner = self.lock();
+        inner.clock += 1;
+        let clock = inner.clock;
+
+        let fresh = match inner.entries.get_mut(&key) {
+            Some(entry) if entry.stored_at.elapsed() < max_age => {
+                entry.last_used = clock;
+                Some(entry.response.clone())
+            }
+            Some(entry) if entry.stored_at.elapsed() >= self.config.ttl => {
+                inner.entries.remove(&key);
+                None
+            }
+            _ => None,
+        };
+
+        match fresh {
+            Some(_) => inner.hits += 1,
+            None => inner.misses += 1,
+        }
+        fresh
+    }
+
+    /// Stores a response, evicting the least recently used one if full
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The search query the response answers
+    /// * `response` - The response to store
+    pub fn insert(&self, query: &SearchQuery, response: SearchResponse) {
+        let key = cache_key(query);
+        let mut inner = self.lock();
+        inner.clock += 1;
+        let clock = inner.clock;
+
+        if !inner.entries.contains_key(&key) && inner.entries.len() >= self.config.capacity {
+            self.evict(&mut inner);
+        }
+
+        inner.entries.insert(
+            key,
+            CacheEntry {
+                response,
+                stored_at: Instant::now(),
+                last_used: clock,
+            },
+        );
+    }
+
+    /// Removes the cached response for a query
+    ///
+    /// # Returns
+    ///
+    /// True if a response was removed
+    pub fn invalidate(&self, query: &SearchQuery) -> bool {
+        self.lock().entries.remove(&cache_key(query)).is_some()
+    }
+
+    /// Removes every cached response
+    pub fn clear(&self) {
+        self.lock().entries.clear();
+    }
+
+    /// Gets the number of stored responses, including expired ones
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Checks if the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.lock().entries.is_empty()
+    }
+
+    /// Gets the usage counters
+    pub fn stats(&self) -> CacheStats {
+        let inner = self.lock();
+        CacheStats {
+            hits: inner.hits,
+            misses: inner.misses,
+            evictions: inner.evictions,
+            entries: inner.entries.len(),
+        }
+    }
+
+    /// Drops expired entries, or the least recently used one if none expired
+    fn evict(&self, inner: &mut CacheInner) {
+        let before = inner.entries.len();
+        inner
+            .entries
+            .retain(|_, entry| entry.stored_at.elapsed() < self.config.ttl);
+        if inner.entries.len() < before {
+            return;
+        }
+
+        let oldest = inner
+            .entries
+            .iter()
+            .min_by_key(|(_, entry)| entry.last_used)
+            .map(|(key, _)| key.clone());
+        if let Some(key) = oldest {
+            inner.entries.remove(&key);
+            inner.evictions += 1;
+        }
+    }
+
+    /// Locks the cache state, recovering from a poisoned lock
+    fn lock(&self) -> std::sync::MutexGuard<'_, CacheInner> {
+        self.inner
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query(q: &str) -> SearchQuery {
+        SearchQuery::new(q.to_string()).unwrap()
+    }
+
+    #[test]
+    fn test_cache_key_normalization() {
+        assert_eq!(
+            cache_key(&query(" rust  lang ")),
+            cache_key(&query("rust lang"))
+        );
+        assert_eq!(
+            cache_key(&query("rust").with_page(1)),
+            cache_key(&query("rust"))
+        );
+        assert_ne!(
+            cache_key(&query("rust").with_page(2)),
+            cache_key(&query("rust"))
+        );
+        assert_ne!(
+            cache_key(&query("rust").with_country("us")),
+            cache_key(&query("rust").with_country("de"))
+        );
+        assert_ne!(
+            cache_key(&query("rust OR go")),
+            cache_key(&query("rust or go"))
+        );
+    }
+
+    #[test]
+    fn test_hits_misses_and_invalidation() {
+        let cache = ResponseCache::new(CacheConfig::new());
+        assert!(cache.get(&query("rust")).is_none());
+
+        cache.insert(&query("rust"), SearchResponse::new());
+        assert_eq!(cache.get(&query(" rust ")), Some(SearchResponse::new()));
+        assert!(
+            cache
+                .get_with_max_age(&query("rust"), Duration::ZERO)
+                .is_none()
+        );
+        assert!(cache.invalidate(&query("rust")));
+        assert!(cache.get(&query("rust")).is_none());
+
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses, stats.entries), (1, 3, 0));
+        assert!((stats.hit_rate() - 0.25).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_ttl_expiry() {
+        let cache = ResponseCache::new(CacheConfig::new().with_ttl(Duration::ZERO));
+        cache.insert(&query("rust"), SearchResponse::new());
+        assert!(cache.get(&query("rust")).is_none());
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_lru_eviction() {
+        let cache = ResponseCache::new(CacheConfig::new().with_capacity(2));
+        cache.insert(&query("a"), SearchResponse::new());
+        cache.insert(&query("b"), SearchResponse::new());
+
+        // Touch "a" so "b" is the least recently used
+        assert!(cache.get(&query("a")).is_some());
+        cache.insert(&query("c"), SearchResponse::new());
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&query("a")).is_some());
+        assert!(cache.get(&query("b")).is_none());
+        assert_eq!(cache.stats().evictions, 1);
+    }
+}
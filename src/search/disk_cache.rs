@@ -0,0 +1,535 @@
+use crate::core::Result;
+/// Persistent response cache module
+///
+/// This module provides a response cache stored as a directory of JSON
+/// files, so completed queries are not billed again after a restart.
+use crate::search::{
+    cache::{CacheStats, cache_key},
+    query::SearchQuery,
+    response::SearchResponse,
+};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Extension of cache entry files
+const ENTRY_EXTENSION: &str = "json";
+
+/// Extension of temporary files written before being renamed into place
+const TEMP_EXTENSION: &str = "tmp";
+
+/// Age after which a temporary file is assumed to be left by a crashed write
+const STALE_TEMP_AGE: Duration = Duration::from_secs(60 * 60);
+
+/// Counter making temporary file names unique within the process
+static TEMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Response cache persisted as one JSON file per query
+///
+/// Files are named after a stable hash of the [`cache_key`] and also store
+/// the key, so hash collisions are detected. Each write goes to its own
+/// temporary file that is renamed into place, so concurrent writers and
+/// crashes never leave a partial entry. Unreadable entries are treated as
+/// misses and removed.
+///
+/// Only files named like entries (`<16 hex digits>.json`) and their
+/// temporary files are ever read or removed, so the directory may hold
+/// other files. Temporary files left by a crashed write are removed after an
+/// hour, when the cache is opened, cleared or purged.
+///
+/// The methods block on file I/O. Clients call them on tokio's blocking
+/// thread pool; call them the same way from async code.
+///
+/// # Example
+///
+/// ```rust
+/// use serper_sdk::search::DiskCache;
+/// use serper_sdk::search::service::SearchServiceBuilder;
+/// use std::time::Duration;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let dir = std::env::temp_dir().join("serper-sdk-doc-cache");
+///     let service = SearchServiceBuilder::new()
+///         .api_key("demo-key-for-docs")
+///         .disk_cache(DiskCache::open(&dir, Duration::from_secs(24 * 60 * 60))?)
+///         .build()?;
+///
+///     let cache = service.disk_cache().unwrap();
+///     println!("{} cached responses", cache.stats().entries);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct DiskCache {
+    dir: PathBuf,
+    ttl: Duration,
+    counters: Mutex<Counters>,
+}
+
+/// Usage counters of a disk cache
+#[derive(Debug, Default)]
+struct Counters {
+    hits: u64,
+    misses: u64,
+}
+
+/// A cache entry as stored on disk
+#[derive(Serialize, Deserialize)]
+struct DiskEntry {
+    key: String,
+    /// Seconds since the Unix epoch when the response was stored
+    stored_at: u64,
+    response: SearchResponse,
+}
+
+impl DiskCache {
+    /// Opens a disk cache, creating the directory if needed
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - Directory holding the cache files
+    /// * `ttl` - How long a stored response stays fresh
+    ///
+    /// # Returns
+    ///
+    /// Result containing the cache or an I/O error
+    pub fn open(dir: impl AsRef<Path>, ttl: Duration) -> Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir)?;
+
+        let cache = Self {
+            dir,
+            ttl,
+            counters: Mutex::new(Counters::default()),
+        };
+        cache.remove_stale_temp_files()?;
+        Ok(cache)
+    }
+
+    /// Gets the cache directory
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Gets how long a stored response stays fresh
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Looks up a response stored less than the TTL ago
+    pub fn get(&self, query: &SearchQuery) -> Option<SearchResponse> {
+        self.get_with_max_age(query, self.ttl)
+    }
+
+    /// Looks up a response stored less than `max_age` ago
+    ///
+    /// `max_age` overrides the TTL for this lookup: a shorter age demands
+    /// fresher data, and a longer one accepts entries past the TTL.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The search query
+    /// * `max_age` - Maximum age of an acceptable response
+    ///
+    /// # Returns
+    ///
+    /// The cached response, or `None` if there is no acceptable entry
+    pub fn get_with_max_age(
+        &self,
+        query: &SearchQuery,
+        max_age: Duration,
+    ) -> Option<SearchResponse> {
+        let key = cache_key(query);
+        let path = self.entry_path(&key);
+
+        let response = match self.read_entry(&path) {
+            Some(entry) if entry.key == key => {
+                let age = age_of(entry.stored_at);
+                if age < max_age {
+                    Some(entry.response)
+                } else {
+                    if age >= self.ttl {
+                        let _ = fs::remove_file(&path);
+                    }
+                    None
+                }
+            }
+            // Missing, or owned by another query with the same hash
+            _ => None,
+        };
+
+        let mut counters = self.lock();
+        match response {
+            Some(_) => counters.hits += 1,
+            None => counters.misses += 1,
+        }
+        response
+    }
+
+    /// Stores a response
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The search query the response answers
+    /// * `response` - The response to store
+    ///
+    /// # Returns
+    ///
+    /// Result indicating success or an I/O error
+    pub fn insert(&self, query: &SearchQuery, response: &SearchResponse) -> Result<()> {
+        let key = cache_key(query);
+        let path = self.entry_path(&key);
+        let entry = DiskEntry {
+            key,
+            stored_at: now_secs(),
+            response: response.clone(),
+        };
+
+        let temp = path.with_extension(format!(
+            "{}-{}.{TEMP_EXTENSION}",
+            std::process::id(),
+            TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let written =
+            fs::write(&temp, serde_json::to_vec(&entry)?).and_then(|()| fs::rename(&temp, &path));
+        if written.is_err() {
+            let _ = fs::remove_file(&temp);
+        }
+        Ok(written?)
+    }
+
+    /// Removes the stored response for a query
+    ///
+    /// # Returns
+    ///
+    /// Result containing true if a response was removed
+    pub fn invalidate(&self, query: &SearchQuery) -> Result<bool> {
+        let key = cache_key(query);
+        let path = self.entry_path(&key);
+
+        match self.read_entry(&path) {
+            Some(entry) if entry.key == key => {
+                fs::remove_file(&path)?;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Removes every stored response
+    ///
+    /// # Returns
+    ///
+    /// Result containing the number of removed entries
+    pub fn clear(&self) -> Result<usize> {
+        self.remove_stale_temp_files()?;
+        self.remove_entries(|_| true)
+    }
+
+    /// Removes entries older than the TTL
+    ///
+    /// # Returns
+    ///
+    /// Result containing the number of removed entries
+    pub fn purge_expired(&self) -> Result<usize> {
+        self.remove_stale_temp_files()?;
+        self.remove_entries(|path| {
+            self.read_entry(path)
+                .is_none_or(|entry| age_of(entry.stored_at) >= self.ttl)
+        })
+    }
+
+    /// Gets the usage counters
+    ///
+    /// Hits and misses are counted since the cache was opened; `entries`
+    /// counts the files currently on disk. Disk caches never evict.
+    pub fn stats(&self) -> CacheStats {
+        let counters = self.lock();
+        CacheStats {
+            hits: counters.hits,
+            misses: counters.misses,
+            evictions: 0,
+            entries: self.entry_paths().map_or(0, |paths| paths.len()),
+        }
+    }
+
+    /// Removes the entry files matching `predicate`
+    fn remove_entries(&self, predicate: impl Fn(&Path) -> bool) -> Result<usize> {
+        let mut removed = 0;
+        for path in self.entry_paths()? {
+            if predicate(&path) {
+                // Unreadable entries may already have been removed by `read_entry`
+                match fs::remove_file(&path) {
+                    Ok(()) => {}
+                    Err(error) if error.kind() == std::io::ErrorKind::NotFound => {}
+                    Err(error) => return Err(error.into()),
+                }
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+
+    /// Removes temporary files older than [`STALE_TEMP_AGE`]
+    ///
+    /// Younger ones may belong to a write in progress in another process.
+    fn remove_stale_temp_files(&self) -> Result<()> {
+        for entry in fs::read_dir(&self.dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if !is_temp_file(&path) {
+                continue;
+            }
+            let stale = entry
+                .metadata()
+                .and_then(|metadata| metadata.modified())
+                .is_ok_and(|modified| modified.elapsed().is_ok_and(|age| age >= STALE_TEMP_AGE));
+            if stale {
+                let _ = fs::remove_file(&path);
+            }
+        }
+        Ok(())
+    }
+
+    /// Lists the entry files in the cache directory
+    fn entry_paths(&self) -> Result<Vec<PathBuf>> {
+        let mut paths = Vec::new();
+        for entry in fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            if is_entry_file(&path) {
+                paths.push(path);
+            }
+        }
+        Ok(paths)
+    }
+
+    /// Reads an entry file, removing it if it cannot be parsed
+    ///
+    /// Files not named like an entry are never read or removed.
+    fn read_entry(&self, path: &Path) -> Option<DiskEntry> {
+        if !is_entry_file(path) {
+            return None;
+        }
+        let bytes = fs::read(path).ok()?;
+        match serde_json::from_slice(&bytes) {
+            Ok(entry) => Some(entry),
+            Err(_) => {
+                let _ = fs::remove_file(path);
+                None
+            }
+        }
+    }
+
+    /// Gets the file path of a cache key
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.dir
+            .join(format!("{:016x}.{ENTRY_EXTENSION}", fnv1a(key.as_bytes())))
+    }
+
+    /// Locks the counters, recovering from a poisoned lock
+    fn lock(&self) -> std::sync::MutexGuard<'_, Counters> {
+        self.counters
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Checks if a file name is a 16-digit lowercase hex hash followed by `suffix`
+fn has_hash_name(path: &Path, suffix: impl Fn(&str) -> bool) -> bool {
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    match (name.get(..16), name.get(16..)) {
+        (Some(hash), Some(rest)) => {
+            hash.bytes()
+                .all(|byte| byte.is_ascii_digit() || (b'a'..=b'f').contains(&byte))
+                && suffix(rest)
+        }
+        _ => false,
+    }
+}
+
+/// Checks if a path names a cache entry file (`<hash>.json`)
+fn is_entry_file(path: &Path) -> bool {
+    has_hash_name(path, |rest| rest.strip_prefix('.') == Some(ENTRY_EXTENSION))
+}
+
+/// Checks if a path names a temporary entry file (`<hash>.<pid>-<n>.tmp`)
+fn is_temp_file(path: &Path) -> bool {
+    has_hash_name(path, |rest| {
+        rest.strip_prefix('.')
+            .and_then(|rest| rest.strip_suffix(TEMP_EXTENSION))
+            .and_then(|rest| rest.strip_suffix('.'))
+            .and_then(|rest| rest.split_once('-'))
+            .is_some_and(|(pid, counter)| {
+                [pid, counter]
+                    .iter()
+                    .all(|part| !part.is_empty() && part.bytes().all(|byte| byte.is_ascii_digit()))
+            })
+    })
+}
+
+/// 64-bit FNV-1a hash, stable across processes and Rust versions
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Gets the current time in seconds since the Unix epoch
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// Gets the age of an entry stored at `stored_at` seconds since the epoch
+fn age_of(stored_at: u64) -> Duration {
+    Duration::from_secs(now_secs().saturating_sub(stored_at))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::response::OrganicResult;
+
+    fn query(q: &str) -> SearchQuery {
+        SearchQuery::new(q.to_string()).unwrap()
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "serper-sdk-disk-cache-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_concurrent_inserts_of_one_query() {
+        let dir = temp_dir("concurrent");
+        let cache = DiskCache::open(&dir, Duration::from_secs(60)).unwrap();
+
+        std::thread::scope(|scope| {
+            for _ in 0..8 {
+                scope.spawn(|| {
+                    for _ in 0..20 {
+                        cache
+                            .insert(&query("rust"), &SearchResponse::new())
+                            .unwrap();
+                    }
+                });
+            }
+        });
+
+        assert_eq!(cache.get(&query("rust")), Some(SearchResponse::new()));
+        // Every temporary file was renamed into the single entry
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_round_trip_survives_reopen() {
+        let dir = temp_dir("round-trip");
+        let mut response = SearchResponse::new();
+        response.organic = Some(vec![OrganicResult::new(
+            "Rust".into(),
+            "https://www.rust-lang.org".into(),
+            1,
+        )]);
+
+        let cache = DiskCache::open(&dir, Duration::from_secs(60)).unwrap();
+        cache.insert(&query("rust"), &response).unwrap();
+
+        let reopened = DiskCache::open(&dir, Duration::from_secs(60)).unwrap();
+        assert_eq!(reopened.get(&query(" rust ")), Some(response));
+        assert!(reopened.get(&query("go")).is_none());
+
+        let stats = reopened.stats();
+        assert_eq!((stats.hits, stats.misses, stats.entries), (1, 1, 1));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_max_age_and_invalidation() {
+        let dir = temp_dir("max-age");
+        let cache = DiskCache::open(&dir, Duration::from_secs(60)).unwrap();
+        cache
+            .insert(&query("rust"), &SearchResponse::new())
+            .unwrap();
+
+        assert!(
+            cache
+                .get_with_max_age(&query("rust"), Duration::ZERO)
+                .is_none()
+        );
+        assert!(cache.get(&query("rust")).is_some());
+
+        assert!(cache.invalidate(&query("rust")).unwrap());
+        assert!(!cache.invalidate(&query("rust")).unwrap());
+        assert!(cache.get(&query("rust")).is_none());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_clear_and_purge() {
+        let dir = temp_dir("clear");
+        let cache = DiskCache::open(&dir, Duration::ZERO).unwrap();
+        cache.insert(&query("a"), &SearchResponse::new()).unwrap();
+        cache.insert(&query("b"), &SearchResponse::new()).unwrap();
+        fs::write(dir.join("00000000000000ff.json"), "not json").unwrap();
+
+        assert_eq!(cache.purge_expired().unwrap(), 3);
+        cache.insert(&query("c"), &SearchResponse::new()).unwrap();
+        assert_eq!(cache.clear().unwrap(), 1);
+        assert_eq!(cache.stats().entries, 0);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_foreign_files_survive() {
+        let dir = temp_dir("foreign");
+        let cache = DiskCache::open(&dir, Duration::ZERO).unwrap();
+        cache.insert(&query("a"), &SearchResponse::new()).unwrap();
+        let foreign = ["settings.json", "00000000000000FF.json", "notes.tmp"];
+        for name in foreign {
+            fs::write(dir.join(name), "not a cache entry").unwrap();
+        }
+
+        assert_eq!(cache.stats().entries, 1);
+        assert_eq!(cache.purge_expired().unwrap(), 1);
+        cache.insert(&query("b"), &SearchResponse::new()).unwrap();
+        assert_eq!(cache.clear().unwrap(), 1);
+        for name in foreign {
+            assert!(dir.join(name).exists(), "{} was removed", name);
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_stale_temp_files_are_removed() {
+        let dir = temp_dir("stale-temp");
+        fs::create_dir_all(&dir).unwrap();
+        let stale = dir.join("00000000000000ff.123-0.tmp");
+        let fresh = dir.join("00000000000000ff.123-1.tmp");
+        fs::write(&stale, "partial").unwrap();
+        fs::write(&fresh, "partial").unwrap();
+        fs::File::options()
+            .write(true)
+            .open(&stale)
+            .unwrap()
+            .set_modified(SystemTime::now() - STALE_TEMP_AGE * 2)
+            .unwrap();
+
+        let cache = DiskCache::open(&dir, Duration::from_secs(60)).unwrap();
+        assert!(!stale.exists());
+        // A recent temporary file may belong to a write in progress
+        assert!(fresh.exists());
+        assert_eq!(cache.clear().unwrap(), 0);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
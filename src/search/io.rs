@@ -0,0 +1,295 @@
+use crate::core::error::{Result, SerperError};
+/// Batch query input/output module
+///
+/// This module provides helpers for loading and saving lists of search
+/// queries as JSON Lines or CSV, with per-row validation.
+use crate::search::query::SearchQuery;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+/// Column order used when writing CSV files
+const CSV_HEADERS: [&str; 7] = ["q", "location", "gl", "hl", "page", "num", "tbs"];
+
+/// File formats supported for batch query I/O
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryFileFormat {
+    /// One JSON object per line
+    JsonLines,
+    /// Comma-separated values with a header row
+    Csv,
+}
+
+impl QueryFileFormat {
+    /// Detects the format from a file extension (`.jsonl`, `.ndjson` or `.csv`)
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_lowercase());
+
+        match extension.as_deref() {
+            Some("jsonl") | Some("ndjson") => Ok(Self::JsonLines),
+            Some("csv") => Ok(Self::Csv),
+            _ => Err(SerperError::validation_error(format!(
+                "Unsupported query file extension: {}",
+                path.display()
+            ))),
+        }
+    }
+}
+
+/// Loads queries from a `.jsonl`/`.ndjson` or `.csv` file
+///
+/// # Arguments
+///
+/// * `path` - The file to read
+///
+/// # Returns
+///
+/// Result containing the queries, or a validation error naming the first invalid row
+pub fn load_queries(path: impl AsRef<Path>) -> Result<Vec<SearchQuery>> {
+    let path = path.as_ref();
+    let format = QueryFileFormat::from_path(path)?;
+    let file = File::open(path)?;
+
+    match format {
+        QueryFileFormat::JsonLines => read_jsonl(BufReader::new(file)),
+        QueryFileFormat::Csv => read_csv(file),
+    }
+}
+
+/// Saves queries to a `.jsonl`/`.ndjson` or `.csv` file
+///
+/// # Arguments
+///
+/// * `path` - The file to create or overwrite
+/// * `queries` - The queries to write
+///
+/// # Returns
+///
+/// Result indicating whether the file was written
+pub fn save_queries(path: impl AsRef<Path>, queries: &[SearchQuery]) -> Result<()> {
+    let path = path.as_ref();
+    let format = QueryFileFormat::from_path(path)?;
+    let mut writer = BufWriter::new(File::create(path)?);
+
+    match format {
+        QueryFileFormat::JsonLines => write_jsonl(&mut writer, queries)?,
+        QueryFileFormat::Csv => write_csv(&mut writer, queries)?,
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+/// Reads queries from JSON Lines, one query object per line
+///
+/// Blank lines are skipped. Row numbers in errors are 1-based line numbers.
+pub fn read_jsonl<R: BufRead>(reader: R) -> Result<Vec<SearchQuery>> {
+    let mut queries = Vec::new();
+
+    for (idx, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let row = idx + 1;
+        let query: SearchQuery =
+            serde_json::from_str(&line).map_err(|e| row_error(row, SerperError::Json(e)))?;
+        query.validate().map_err(|e| row_error(row, e))?;
+        queries.push(query);
+    }
+
+    Ok(queries)
+}
+
+/// Writes queries as JSON Lines, one query object per line
+pub fn write_jsonl<W: Write>(mut writer: W, queries: &[SearchQuery]) -> Result<()> {
+    for query in queries {
+        serde_json::to_writer(&mut writer, query)?;
+        writer.write_all(b"\n")?;
+    }
+    Ok(())
+}
+
+/// Reads queries from CSV with a header row
+///
+/// Only the `q` column is required; the other columns (`location`, `gl`,
+/// `hl`, `page`, `num`, `tbs`) may be omitted or left empty. Row numbers in
+/// errors are 1-based line numbers, so the first data row is row 2.
+pub fn read_csv<R: Read>(reader: R) -> Result<Vec<SearchQuery>> {
+    let mut reader = csv::Reader::from_reader(reader);
+    let headers = reader.headers().map_err(csv_error)?.clone();
+    let mut record = csv::StringRecord::new();
+    let mut queries = Vec::new();
+
+    while reader.read_record(&mut record).map_err(csv_error)? {
+        let row = record
+            .position()
+            .map_or(0, |position| position.line() as usize);
+        let query: SearchQuery = record
+            .deserialize(Some(&headers))
+            .map_err(|e| row_error(row, SerperError::validation_error(e.to_string())))?;
+        query.validate().map_err(|e| row_error(row, e))?;
+        queries.push(query);
+    }
+
+    Ok(queries)
+}
+
+/// Writes queries as CSV with a header row
+pub fn write_csv<W: Write>(writer: W, queries: &[SearchQuery]) -> Result<()> {
+    let mut writer = csv::Writer::from_writer(writer);
+    writer.write_record(CSV_HEADERS).map_err(csv_error)?;
+
+    for query in queries {
+        let optional = |value: Option<String>| value.unwrap_or_default();
+        writer
+            .write_record([
+                query.q.clone(),
+                optional(query.location.clone()),
+                optional(query.gl.as_ref().map(|gl| gl.to_string())),
+                optional(query.hl.as_ref().map(|hl| hl.to_string())),
+                optional(query.page.map(|page| page.to_string())),
+                optional(query.num.map(|num| num.to_string())),
+                optional(query.tbs.clone()),
+            ])
+            .map_err(csv_error)?;
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+/// Wraps an error in a validation error that names the offending row
+fn row_error(row: usize, error: SerperError) -> SerperError {
+    let message = match error {
+        SerperError::Validation { message } => message,
+        other => other.to_string(),
+    };
+    SerperError::validation_error(format!("Row {}: {}", row, message))
+}
+
+/// Converts a CSV error, keeping I/O failures distinct from malformed rows
+fn csv_error(error: csv::Error) -> SerperError {
+    if error.is_io_error() {
+        return SerperError::Io(error.into());
+    }
+
+    match error.position() {
+        Some(position) => row_error(
+            position.line() as usize,
+            SerperError::validation_error(error.to_string()),
+        ),
+        None => SerperError::validation_error(error.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_queries() -> Vec<SearchQuery> {
+        vec![
+            SearchQuery::new("rust, async".to_string())
+                .unwrap()
+                .with_location("Paris".to_string())
+                .with_country("fr")
+                .with_language("en")
+                .with_page(2)
+                .with_num_results(20)
+                .with_tbs("qdr:d".to_string()),
+            SearchQuery::new("plain \"quoted\" query".to_string()).unwrap(),
+        ]
+    }
+
+    #[test]
+    fn test_jsonl_round_trip() {
+        let mut buffer = Vec::new();
+        write_jsonl(&mut buffer, &sample_queries()).unwrap();
+
+        let text = String::from_utf8(buffer.clone()).unwrap();
+        assert_eq!(text.lines().count(), 2);
+
+        let queries = read_jsonl(buffer.as_slice()).unwrap();
+        assert_eq!(queries, sample_queries());
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let mut buffer = Vec::new();
+        write_csv(&mut buffer, &sample_queries()).unwrap();
+
+        let text = String::from_utf8(buffer.clone()).unwrap();
+        assert!(text.starts_with("q,location,gl,hl,page,num,tbs\n"));
+
+        let queries = read_csv(buffer.as_slice()).unwrap();
+        assert_eq!(queries, sample_queries());
+    }
+
+    #[test]
+    fn test_csv_optional_columns() {
+        let queries = read_csv("q,gl\ncoffee,us\ntea,\n".as_bytes()).unwrap();
+        assert_eq!(queries.len(), 2);
+        assert_eq!(queries[0].gl, Some("us".into()));
+        assert_eq!(queries[1].gl, None);
+    }
+
+    #[test]
+    fn test_invalid_rows_report_row_number() {
+        let jsonl = "{\"q\": \"ok\"}\n\n{\"q\": \"bad\", \"page\": 0}\n";
+        match read_jsonl(jsonl.as_bytes()).unwrap_err() {
+            SerperError::Validation { message } => {
+                assert_eq!(message, "Row 3: Page number must be greater than 0");
+            }
+            other => panic!("Expected validation error, got {:?}", other),
+        }
+
+        let jsonl = "{\"q\": \"ok\"}\nnot json\n";
+        match read_jsonl(jsonl.as_bytes()).unwrap_err() {
+            SerperError::Validation { message } => assert!(message.starts_with("Row 2: ")),
+            other => panic!("Expected validation error, got {:?}", other),
+        }
+
+        let csv = "q,gl\nok,us\nbad,france\n";
+        match read_csv(csv.as_bytes()).unwrap_err() {
+            SerperError::Validation { message } => {
+                assert!(message.starts_with("Row 3: Invalid country code 'france'"));
+            }
+            other => panic!("Expected validation error, got {:?}", other),
+        }
+
+        let csv = "q,page\nok,x\n";
+        match read_csv(csv.as_bytes()).unwrap_err() {
+            SerperError::Validation { message } => assert!(message.starts_with("Row 2: ")),
+            other => panic!("Expected validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_file_format_detection() {
+        assert_eq!(
+            QueryFileFormat::from_path("jobs/queries.JSONL").unwrap(),
+            QueryFileFormat::JsonLines
+        );
+        assert_eq!(
+            QueryFileFormat::from_path("queries.csv").unwrap(),
+            QueryFileFormat::Csv
+        );
+        assert!(QueryFileFormat::from_path("queries.txt").is_err());
+    }
+
+    #[test]
+    fn test_save_and_load_file() {
+        let path = std::env::temp_dir().join(format!("serper-io-{}.jsonl", std::process::id()));
+
+        save_queries(&path, &sample_queries()).unwrap();
+        let loaded = load_queries(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, sample_queries());
+    }
+}
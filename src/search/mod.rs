@@ -2,13 +2,28 @@
 ///
 /// This module provides comprehensive functionality for building search queries,
 /// handling responses, and orchestrating search operations.
+pub mod cache;
+#[cfg(feature = "disk-cache")]
+pub mod disk_cache;
+pub mod io;
+pub mod paginate;
 pub mod query;
+pub mod report;
 pub mod response;
+pub mod result_set;
 pub mod service;
+pub mod template;
 
+pub use cache::{CacheConfig, CacheStats, ResponseCache};
+#[cfg(feature = "disk-cache")]
+pub use disk_cache::DiskCache;
+pub use paginate::PaginationLimits;
 pub use query::{SearchQuery, SearchQueryBuilder};
+pub use report::{BatchItem, BatchReport};
 pub use response::{
     AnswerBox, KnowledgeGraph, NewsResult, OrganicResult, RelatedQuestion, ResponseParser,
     SearchMetadata, SearchResponse, ShoppingResult,
 };
+pub use result_set::{RankedResult, ResultSet};
 pub use service::SearchService;
+pub use template::{ExpansionMode, QueryTemplate};
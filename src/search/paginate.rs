@@ -0,0 +1,169 @@
+use crate::core::error::Result;
+/// Automatic pagination module
+///
+/// This module provides a stream that walks result pages of a query and
+/// yields organic results with positions numbered across all pages.
+use crate::http::SerperHttpClient;
+use crate::search::{query::SearchQuery, response::OrganicResult};
+use futures::{Stream, StreamExt, stream};
+
+/// Default number of pages fetched when no limit is configured
+pub const DEFAULT_MAX_PAGES: u32 = 10;
+
+/// Limits that stop automatic pagination
+///
+/// Pagination always stops at the first empty page; these limits stop it
+/// earlier. The default fetches at most [`DEFAULT_MAX_PAGES`] pages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaginationLimits {
+    /// Maximum number of organic results to yield
+    pub max_results: Option<usize>,
+    /// Maximum number of pages to request
+    pub max_pages: Option<u32>,
+}
+
+impl PaginationLimits {
+    /// Creates limits with the default page cap and no result cap
+    pub fn new() -> Self {
+        Self {
+            max_results: None,
+            max_pages: Some(DEFAULT_MAX_PAGES),
+        }
+    }
+
+    /// Creates limits that only stop on an empty page
+    pub fn unlimited() -> Self {
+        Self {
+            max_results: None,
+            max_pages: None,
+        }
+    }
+
+    /// Sets the maximum number of organic results to yield
+    pub fn with_max_results(mut self, max_results: usize) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    /// Sets the maximum number of pages to request
+    pub fn with_max_pages(mut self, max_pages: u32) -> Self {
+        self.max_pages = Some(max_pages);
+        self
+    }
+}
+
+impl Default for PaginationLimits {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Progress of a pagination walk
+struct PageState {
+    client: SerperHttpClient,
+    query: SearchQuery,
+    limits: PaginationLimits,
+    next_page: u32,
+    first_position: usize,
+    pages_fetched: u32,
+    yielded: usize,
+    finished: bool,
+}
+
+impl PageState {
+    /// Checks if no further page should be requested
+    fn is_exhausted(&self) -> bool {
+        self.finished
+            || self
+                .limits
+                .max_pages
+                .is_some_and(|max| self.pages_fetched >= max)
+            || self
+                .limits
+                .max_results
+                .is_some_and(|max| self.yielded >= max)
+    }
+}
+
+/// Walks the pages of a query, yielding organic results in order
+///
+/// Pages are requested one at a time starting from the query's page (or 1).
+/// Result positions are renumbered across all pages, counting from the
+/// start page's first result, so page 2 of a 10-result query starts at 11.
+/// An error is yielded once and ends the stream.
+pub(crate) fn paginate(
+    client: SerperHttpClient,
+    query: SearchQuery,
+    limits: PaginationLimits,
+) -> impl Stream<Item = Result<OrganicResult>> + 'static {
+    let start_page = query.page.unwrap_or(1);
+    let per_page = query.num.unwrap_or(10);
+    let state = PageState {
+        client,
+        next_page: start_page,
+        first_position: start_page.saturating_sub(1).saturating_mul(per_page) as usize + 1,
+        query,
+        limits,
+        pages_fetched: 0,
+        yielded: 0,
+        finished: false,
+    };
+
+    stream::unfold(state, |mut state| async move {
+        if state.is_exhausted() {
+            return None;
+        }
+
+        let query = state.query.clone().with_page(state.next_page);
+        state.next_page += 1;
+        state.pages_fetched += 1;
+
+        let items = match state.client.search(&query).await {
+            Ok(response) => {
+                let organic = response.organic.unwrap_or_default();
+                if organic.is_empty() {
+                    return None;
+                }
+
+                let remaining = state
+                    .limits
+                    .max_results
+                    .map_or(usize::MAX, |max| max - state.yielded);
+                organic
+                    .into_iter()
+                    .take(remaining)
+                    .map(|mut result| {
+                        result.position = (state.first_position + state.yielded) as u32;
+                        state.yielded += 1;
+                        Ok(result)
+                    })
+                    .collect::<Vec<_>>()
+            }
+            Err(error) => {
+                state.finished = true;
+                vec![Err(error)]
+            }
+        };
+
+        Some((stream::iter(items), state))
+    })
+    .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pagination_limits() {
+        let limits = PaginationLimits::default();
+        assert_eq!(limits.max_pages, Some(DEFAULT_MAX_PAGES));
+        assert_eq!(limits.max_results, None);
+
+        let limits = PaginationLimits::unlimited()
+            .with_max_results(100)
+            .with_max_pages(12);
+        assert_eq!(limits.max_results, Some(100));
+        assert_eq!(limits.max_pages, Some(12));
+    }
+}
@@ -1,6 +1,6 @@
 use crate::core::{
     error::{Result, SerperError},
-    types::{Location, Pagination},
+    types::{CountryCode, LanguageCode, Location, Pagination},
 };
 /// Search query construction and validation module
 ///
@@ -23,11 +23,11 @@ pub struct SearchQuery {
 
     /// Optional country code (gl parameter)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub gl: Option<String>,
+    pub gl: Option<CountryCode>,
 
     /// Optional language code (hl parameter)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub hl: Option<String>,
+    pub hl: Option<LanguageCode>,
 
     /// Optional page number for pagination
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -36,6 +36,10 @@ pub struct SearchQuery {
     /// Optional number of results per page
     #[serde(skip_serializing_if = "Option::is_none")]
     pub num: Option<u32>,
+
+    /// Optional Google search filter (tbs parameter, e.g. "qdr:d" for the past day)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tbs: Option<String>,
 }
 
 impl SearchQuery {
@@ -62,6 +66,7 @@ impl SearchQuery {
             hl: None,
             page: None,
             num: None,
+            tbs: None,
         })
     }
 
@@ -75,13 +80,38 @@ impl SearchQuery {
         self
     }
 
+    /// Sets the location to the canonical match for a place name
+    ///
+    /// Resolves the name against the embedded location table and also sets
+    /// the country code when none has been set yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The place name (e.g., "Paris", "San Francisco, CA")
+    ///
+    /// # Returns
+    ///
+    /// Result containing the updated query or a validation error if the
+    /// location is unknown
+    #[cfg(feature = "locations")]
+    pub fn with_resolved_location(mut self, name: &str) -> Result<Self> {
+        let resolved = Location::resolve(name)
+            .ok_or_else(|| SerperError::validation_error(format!("Unknown location '{}'", name)))?;
+
+        self.location = Some(resolved.canonical_name.to_string());
+        if self.gl.is_none() {
+            self.gl = Some(resolved.country_code);
+        }
+        Ok(self)
+    }
+
     /// Sets the country code for the search query
     ///
     /// # Arguments
     ///
     /// * `country` - The country code (e.g., "fr", "us")
-    pub fn with_country(mut self, country: String) -> Self {
-        self.gl = Some(country);
+    pub fn with_country(mut self, country: impl Into<CountryCode>) -> Self {
+        self.gl = Some(country.into());
         self
     }
 
@@ -90,8 +120,8 @@ impl SearchQuery {
     /// # Arguments
     ///
     /// * `language` - The language code (e.g., "en", "fr")
-    pub fn with_language(mut self, language: String) -> Self {
-        self.hl = Some(language);
+    pub fn with_language(mut self, language: impl Into<LanguageCode>) -> Self {
+        self.hl = Some(language.into());
         self
     }
 
@@ -115,6 +145,16 @@ impl SearchQuery {
         self
     }
 
+    /// Sets the Google search filter (tbs parameter)
+    ///
+    /// # Arguments
+    ///
+    /// * `tbs` - The filter string (e.g., "qdr:d", "qdr:w")
+    pub fn with_tbs(mut self, tbs: String) -> Self {
+        self.tbs = Some(tbs);
+        self
+    }
+
     /// Applies location settings from a Location struct
     ///
     /// # Arguments
@@ -150,6 +190,9 @@ impl SearchQuery {
 
     /// Validates the search query parameters
     ///
+    /// With the `locations` feature, `location` must resolve against the
+    /// embedded location table.
+    ///
     /// # Returns
     ///
     /// Result indicating whether the query is valid
@@ -160,6 +203,24 @@ impl SearchQuery {
             ));
         }
 
+        if let Some(gl) = &self.gl {
+            gl.validate()?;
+        }
+
+        if let Some(hl) = &self.hl {
+            hl.validate()?;
+        }
+
+        #[cfg(feature = "locations")]
+        if let Some(location) = &self.location
+            && Location::resolve(location).is_none()
+        {
+            return Err(SerperError::validation_error(format!(
+                "Unknown location '{}'",
+                location
+            )));
+        }
+
         if let Some(page) = self.page
             && page == 0
         {
@@ -193,16 +254,264 @@ impl SearchQuery {
     pub fn has_pagination_params(&self) -> bool {
         self.page.is_some() || self.num.is_some()
     }
+
+    /// Creates a search query from a Google search URL
+    ///
+    /// Maps the `q`, `gl`, `hl`, `num`, `start` and `tbs` URL parameters onto
+    /// the query. Google's `start` result offset is converted to a 1-based page
+    /// number using `num` (or Google's default of 10 results per page).
+    /// Tracking parameters that do not change the results (such as `oq` or
+    /// `ved`) are ignored; any other parameter, such as `tbm` or `safe`, has no
+    /// Serper equivalent and is rejected so the URL is never replayed as a
+    /// different search.
+    ///
+    /// # Arguments
+    ///
+    /// * `google_url` - A URL such as `https://www.google.com/search?q=rust&hl=fr`
+    ///
+    /// # Returns
+    ///
+    /// Result containing the SearchQuery or a validation error if the URL is
+    /// not a Google search URL, has no query or has an unsupported parameter
+    pub fn from_google_url(google_url: &str) -> Result<Self> {
+        let url = url::Url::parse(google_url)
+            .map_err(|_| SerperError::validation_error(format!("Invalid URL: {}", google_url)))?;
+
+        if !url.host_str().is_some_and(is_google_host) || url.path() != "/search" {
+            return Err(SerperError::validation_error(format!(
+                "Not a Google search URL: {}",
+                google_url
+            )));
+        }
+
+        let mut q = None;
+        let mut start = None;
+        let mut query = Self {
+            q: String::new(),
+            location: None,
+            gl: None,
+            hl: None,
+            page: None,
+            num: None,
+            tbs: None,
+        };
+
+        for (key, value) in url.query_pairs() {
+            let value = value.into_owned();
+            match key.as_ref() {
+                "q" => q = Some(value),
+                "gl" => query.gl = Some(value.into()),
+                "hl" => query.hl = Some(value.into()),
+                "tbs" => query.tbs = Some(value),
+                "num" => query.num = Some(parse_url_number("num", &value)?),
+                "start" => start = Some(parse_url_number("start", &value)?),
+                other if GOOGLE_IGNORED_PARAMS.contains(&other) => {}
+                other => {
+                    return Err(SerperError::validation_error(format!(
+                        "Unsupported Google URL parameter: {}",
+                        other
+                    )));
+                }
+            }
+        }
+
+        query.q =
+            q.ok_or_else(|| SerperError::validation_error("Google URL has no q parameter"))?;
+
+        if let Some(start) = start {
+            let per_page = query.num.unwrap_or(GOOGLE_RESULTS_PER_PAGE).max(1);
+            if start % per_page != 0 {
+                return Err(SerperError::validation_error(format!(
+                    "Google URL start offset {} is not a multiple of {} results per page",
+                    start, per_page
+                )));
+            }
+            query.page = Some(start / per_page + 1);
+        }
+
+        query.validate()?;
+        Ok(query)
+    }
+
+    /// Converts the search query to an equivalent Google search URL
+    ///
+    /// The page number is converted back to Google's `start` result offset.
+    /// The `location` field has no plain-text Google URL equivalent and is
+    /// not included.
+    ///
+    /// # Returns
+    ///
+    /// The google.com search URL as a string
+    pub fn to_google_url(&self) -> String {
+        let mut url =
+            url::Url::parse("https://www.google.com/search").expect("static URL is valid");
+
+        {
+            let mut pairs = url.query_pairs_mut();
+            pairs.append_pair("q", &self.q);
+            if let Some(gl) = &self.gl {
+                pairs.append_pair("gl", gl.as_str());
+            }
+            if let Some(hl) = &self.hl {
+                pairs.append_pair("hl", hl.as_str());
+            }
+            if let Some(num) = self.num {
+                pairs.append_pair("num", &num.to_string());
+            }
+            if let Some(page) = self.page
+                && page > 1
+            {
+                let per_page = self.num.unwrap_or(GOOGLE_RESULTS_PER_PAGE);
+                pairs.append_pair("start", &(page - 1).saturating_mul(per_page).to_string());
+            }
+            if let Some(tbs) = &self.tbs {
+                pairs.append_pair("tbs", tbs);
+            }
+        }
+
+        url.into()
+    }
+}
+
+impl SearchQuery {
+    /// Parses a search query from a URL query string
+    ///
+    /// Accepts the parameters produced by [`SearchQuery::to_query_string`]
+    /// (`q`, `location`, `gl`, `hl`, `page`, `num`, `tbs`); a leading `?` is
+    /// ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_string` - The query string (e.g., `q=rust&gl=us&page=2`)
+    ///
+    /// # Returns
+    ///
+    /// Result containing the validated SearchQuery or a validation error
+    pub fn from_query_string(query_string: &str) -> Result<Self> {
+        let query_string = query_string.strip_prefix('?').unwrap_or(query_string);
+
+        let mut q = None;
+        let mut query = Self {
+            q: String::new(),
+            location: None,
+            gl: None,
+            hl: None,
+            page: None,
+            num: None,
+            tbs: None,
+        };
+
+        for (key, value) in url::form_urlencoded::parse(query_string.as_bytes()) {
+            let value = value.into_owned();
+            match key.as_ref() {
+                "q" => q = Some(value),
+                "location" => query.location = Some(value),
+                "gl" => query.gl = Some(value.into()),
+                "hl" => query.hl = Some(value.into()),
+                "page" => query.page = Some(parse_url_number("page", &value)?),
+                "num" => query.num = Some(parse_url_number("num", &value)?),
+                "tbs" => query.tbs = Some(value),
+                other => {
+                    return Err(SerperError::validation_error(format!(
+                        "Unknown query parameter: {}",
+                        other
+                    )));
+                }
+            }
+        }
+
+        query.q = q.ok_or_else(|| SerperError::validation_error("Query string is required"))?;
+        query.validate()?;
+        Ok(query)
+    }
+
+    /// Serializes the search query to a URL query string
+    ///
+    /// # Returns
+    ///
+    /// The form-encoded parameters (e.g., `q=rust+async&gl=us&page=2`)
+    pub fn to_query_string(&self) -> String {
+        let mut serializer = url::form_urlencoded::Serializer::new(String::new());
+        serializer.append_pair("q", &self.q);
+        if let Some(location) = &self.location {
+            serializer.append_pair("location", location);
+        }
+        if let Some(gl) = &self.gl {
+            serializer.append_pair("gl", gl.as_str());
+        }
+        if let Some(hl) = &self.hl {
+            serializer.append_pair("hl", hl.as_str());
+        }
+        if let Some(page) = self.page {
+            serializer.append_pair("page", &page.to_string());
+        }
+        if let Some(num) = self.num {
+            serializer.append_pair("num", &num.to_string());
+        }
+        if let Some(tbs) = &self.tbs {
+            serializer.append_pair("tbs", tbs);
+        }
+        serializer.finish()
+    }
+}
+
+/// Number of results Google shows per page when `num` is not specified
+const GOOGLE_RESULTS_PER_PAGE: u32 = 10;
+
+/// Google URL parameters that track the session or browser without changing
+/// the results
+const GOOGLE_IGNORED_PARAMS: &[&str] = &[
+    "aqs", "bih", "biw", "client", "dpr", "ei", "fbs", "gs_lcp", "gs_lp", "gs_ssp", "ie", "iflsig",
+    "oe", "oq", "rlz", "sa", "sca_esv", "sca_upv", "sclient", "source", "sourceid", "sxsrf",
+    "uact", "ved",
+];
+
+/// Domain suffixes of Google Search, after `google.`
+const GOOGLE_DOMAIN_SUFFIXES: &[&str] = &[
+    "ad", "ae", "al", "am", "as", "at", "az", "ba", "be", "bf", "bg", "bi", "bj", "bs", "bt", "by",
+    "ca", "cat", "cd", "cf", "cg", "ch", "ci", "cl", "cm", "cn", "co.ao", "co.bw", "co.ck",
+    "co.cr", "co.id", "co.il", "co.in", "co.jp", "co.ke", "co.kr", "co.ls", "co.ma", "co.mz",
+    "co.nz", "co.th", "co.tz", "co.ug", "co.uk", "co.uz", "co.ve", "co.vi", "co.za", "co.zm",
+    "co.zw", "com", "com.af", "com.ag", "com.ar", "com.au", "com.bd", "com.bh", "com.bn", "com.bo",
+    "com.br", "com.bz", "com.co", "com.cu", "com.cy", "com.do", "com.ec", "com.eg", "com.et",
+    "com.fj", "com.gh", "com.gi", "com.gt", "com.hk", "com.jm", "com.kh", "com.kw", "com.lb",
+    "com.ly", "com.mm", "com.mt", "com.mx", "com.my", "com.na", "com.ng", "com.ni", "com.np",
+    "com.om", "com.pa", "com.pe", "com.pg", "com.ph", "com.pk", "com.pr", "com.py", "com.qa",
+    "com.sa", "com.sb", "com.sg", "com.sl", "com.sv", "com.tj", "com.tr", "com.tw", "com.ua",
+    "com.uy", "com.vc", "com.vn", "cv", "cz", "de", "dj", "dk", "dm", "dz", "ee", "es", "fi", "fm",
+    "fr", "ga", "ge", "gg", "gl", "gm", "gr", "gy", "hn", "hr", "ht", "hu", "ie", "im", "iq", "is",
+    "it", "je", "jo", "kg", "ki", "kz", "la", "li", "lk", "lt", "lu", "lv", "md", "me", "mg", "mk",
+    "ml", "mn", "mu", "mv", "mw", "ne", "nl", "no", "nr", "nu", "pl", "pn", "ps", "pt", "ro", "rs",
+    "ru", "rw", "sc", "se", "sh", "si", "sk", "sm", "sn", "so", "sr", "st", "td", "tg", "tl", "tm",
+    "tn", "to", "tt", "vu", "ws",
+];
+
+/// Checks if a host is a Google search domain
+///
+/// Accepts `google.<suffix>` for the suffixes in [`GOOGLE_DOMAIN_SUFFIXES`],
+/// with or without a leading `www.`, and nothing else.
+fn is_google_host(host: &str) -> bool {
+    let host = host.strip_prefix("www.").unwrap_or(host);
+    host.strip_prefix("google.")
+        .is_some_and(|suffix| GOOGLE_DOMAIN_SUFFIXES.contains(&suffix))
+}
+
+/// Parses a numeric Google URL parameter
+fn parse_url_number(name: &str, value: &str) -> Result<u32> {
+    value.parse().map_err(|_| {
+        SerperError::validation_error(format!("Invalid {} parameter in URL: {}", name, value))
+    })
 }
 
 /// Builder for creating search queries with validation
 pub struct SearchQueryBuilder {
     query: Option<String>,
     location: Option<String>,
-    country: Option<String>,
-    language: Option<String>,
+    country: Option<CountryCode>,
+    language: Option<LanguageCode>,
     page: Option<u32>,
     num_results: Option<u32>,
+    tbs: Option<String>,
 }
 
 impl SearchQueryBuilder {
@@ -215,6 +524,7 @@ impl SearchQueryBuilder {
             language: None,
             page: None,
             num_results: None,
+            tbs: None,
         }
     }
 
@@ -231,13 +541,13 @@ impl SearchQueryBuilder {
     }
 
     /// Sets the country code
-    pub fn country(mut self, country: impl Into<String>) -> Self {
+    pub fn country(mut self, country: impl Into<CountryCode>) -> Self {
         self.country = Some(country.into());
         self
     }
 
     /// Sets the language code
-    pub fn language(mut self, language: impl Into<String>) -> Self {
+    pub fn language(mut self, language: impl Into<LanguageCode>) -> Self {
         self.language = Some(language.into());
         self
     }
@@ -254,6 +564,12 @@ impl SearchQueryBuilder {
         self
     }
 
+    /// Sets the Google search filter (tbs parameter)
+    pub fn tbs(mut self, tbs: impl Into<String>) -> Self {
+        self.tbs = Some(tbs.into());
+        self
+    }
+
     /// Builds the search query with validation
     pub fn build(self) -> Result<SearchQuery> {
         let query = self
@@ -277,6 +593,9 @@ impl SearchQueryBuilder {
         if let Some(num) = self.num_results {
             search_query = search_query.with_num_results(num);
         }
+        if let Some(tbs) = self.tbs {
+            search_query = search_query.with_tbs(tbs);
+        }
 
         search_query.validate()?;
         Ok(search_query)
@@ -332,8 +651,8 @@ mod tests {
 
         assert_eq!(query.q, "test query");
         assert_eq!(query.location, Some("Paris".to_string()));
-        assert_eq!(query.gl, Some("fr".to_string()));
-        assert_eq!(query.hl, Some("en".to_string()));
+        assert_eq!(query.gl, Some("fr".into()));
+        assert_eq!(query.hl, Some("en".into()));
         assert_eq!(query.page, Some(1));
         assert_eq!(query.num, Some(10));
     }
@@ -349,6 +668,18 @@ mod tests {
             .with_num_results(101);
 
         assert!(query.validate().is_err());
+
+        let query = SearchQuery::new("test".to_string())
+            .unwrap()
+            .with_country("france");
+
+        assert!(query.validate().is_err());
+
+        let query = SearchQuery::new("test".to_string())
+            .unwrap()
+            .with_language("xx");
+
+        assert!(query.validate().is_err());
     }
 
     #[test]
@@ -362,4 +693,182 @@ mod tests {
         assert!(query.has_pagination_params());
         assert_eq!(query.query(), "test");
     }
+
+    #[cfg(feature = "locations")]
+    #[test]
+    fn test_with_resolved_location() {
+        let query = SearchQuery::new("test".to_string())
+            .unwrap()
+            .with_resolved_location("Munich")
+            .unwrap();
+
+        assert_eq!(query.location.as_deref(), Some("Munich,Bavaria,Germany"));
+        assert_eq!(query.gl, Some("de".into()));
+
+        let query = SearchQuery::new("test".to_string())
+            .unwrap()
+            .with_country("us")
+            .with_resolved_location("Berlin")
+            .unwrap();
+        assert_eq!(query.gl, Some("us".into()));
+
+        let result = SearchQuery::new("test".to_string())
+            .unwrap()
+            .with_resolved_location("Atlantis");
+        assert!(result.is_err());
+    }
+
+    #[cfg(feature = "locations")]
+    #[test]
+    fn test_validate_checks_location() {
+        let query = SearchQuery::new("test".to_string()).unwrap();
+        assert!(
+            query
+                .clone()
+                .with_location("Paris, France".to_string())
+                .validate()
+                .is_ok()
+        );
+
+        let error = query
+            .with_location("Atlantis".to_string())
+            .validate()
+            .unwrap_err();
+        assert!(error.to_string().contains("Unknown location 'Atlantis'"));
+    }
+
+    #[test]
+    fn test_from_google_url() {
+        let query = SearchQuery::from_google_url(
+            "https://www.google.com/search?q=rust+async&gl=fr&hl=en&num=20&start=40&tbs=qdr:w",
+        )
+        .unwrap();
+
+        assert_eq!(query.q, "rust async");
+        assert_eq!(query.gl, Some("fr".into()));
+        assert_eq!(query.hl, Some("en".into()));
+        assert_eq!(query.num, Some(20));
+        assert_eq!(query.page, Some(3));
+        assert_eq!(query.tbs, Some("qdr:w".to_string()));
+    }
+
+    #[test]
+    fn test_from_google_url_default_page_size() {
+        let query =
+            SearchQuery::from_google_url("https://google.co.uk/search?q=test&start=10").unwrap();
+
+        assert_eq!(query.page, Some(2));
+        assert_eq!(query.num, None);
+
+        // An offset between pages has no page number
+        let error =
+            SearchQuery::from_google_url("https://google.com/search?q=test&start=15&num=10")
+                .unwrap_err();
+        assert!(error.to_string().contains("start offset 15"));
+    }
+
+    #[test]
+    fn test_to_google_url_saturates_start_offset() {
+        let query = SearchQuery::new("test".to_string())
+            .unwrap()
+            .with_page(u32::MAX)
+            .with_num_results(100);
+        assert!(
+            query
+                .to_google_url()
+                .contains(&format!("start={}", u32::MAX))
+        );
+    }
+
+    #[test]
+    fn test_from_google_url_rejects_invalid_urls() {
+        assert!(SearchQuery::from_google_url("not a url").is_err());
+        assert!(SearchQuery::from_google_url("https://www.bing.com/search?q=test").is_err());
+        assert!(SearchQuery::from_google_url("https://www.google.com/maps?q=test").is_err());
+        assert!(SearchQuery::from_google_url("https://www.google.com/search?hl=en").is_err());
+        assert!(SearchQuery::from_google_url("https://www.google.com/search?q=a&num=x").is_err());
+    }
+
+    #[test]
+    fn test_from_google_url_checks_host() {
+        for host in [
+            "google.com",
+            "www.google.de",
+            "google.co.uk",
+            "www.google.com.au",
+        ] {
+            let url = format!("https://{}/search?q=test", host);
+            assert!(SearchQuery::from_google_url(&url).is_ok(), "{}", host);
+        }
+        for host in [
+            "google.evil.com",
+            "www.google.com.evil.io",
+            "notgoogle.com",
+            "google.co.uk.io",
+            "google.zz",
+            "google.com.xy",
+        ] {
+            let url = format!("https://{}/search?q=test", host);
+            assert!(SearchQuery::from_google_url(&url).is_err(), "{}", host);
+        }
+    }
+
+    #[test]
+    fn test_from_google_url_rejects_unsupported_params() {
+        let error = SearchQuery::from_google_url("https://www.google.com/search?q=cats&tbm=isch")
+            .unwrap_err();
+        assert!(error.to_string().contains("tbm"));
+
+        let query = SearchQuery::from_google_url(
+            "https://www.google.com/search?q=cats&oq=cats&sourceid=chrome&ie=UTF-8",
+        )
+        .unwrap();
+        assert_eq!(query.q, "cats");
+    }
+
+    #[test]
+    fn test_google_url_round_trip() {
+        let query = SearchQuery::new("rust & tokio".to_string())
+            .unwrap()
+            .with_country("de")
+            .with_language("de")
+            .with_page(2)
+            .with_num_results(10)
+            .with_tbs("qdr:d".to_string());
+
+        let url = query.to_google_url();
+        assert!(url.starts_with("https://www.google.com/search?q=rust+%26+tokio"));
+        assert!(url.contains("start=10"));
+
+        let parsed = SearchQuery::from_google_url(&url).unwrap();
+        assert_eq!(parsed, query);
+    }
+
+    #[test]
+    fn test_query_string_round_trip() {
+        let query = SearchQuery::new("coffee & tea".to_string())
+            .unwrap()
+            .with_location("Paris, France".to_string())
+            .with_country("fr")
+            .with_page(3);
+
+        let encoded = query.to_query_string();
+        assert_eq!(
+            encoded,
+            "q=coffee+%26+tea&location=Paris%2C+France&gl=fr&page=3"
+        );
+        assert_eq!(SearchQuery::from_query_string(&encoded).unwrap(), query);
+        assert_eq!(
+            SearchQuery::from_query_string(&format!("?{}", encoded)).unwrap(),
+            query
+        );
+    }
+
+    #[test]
+    fn test_query_string_rejects_invalid_input() {
+        assert!(SearchQuery::from_query_string("gl=us").is_err());
+        assert!(SearchQuery::from_query_string("q=test&foo=bar").is_err());
+        assert!(SearchQuery::from_query_string("q=test&page=0").is_err());
+        assert!(SearchQuery::from_query_string("q=test&num=abc").is_err());
+    }
 }
@@ -0,0 +1,148 @@
+use crate::core::error::{Result, SerperError};
+/// Batch search reporting module
+///
+/// This module provides the report returned by partial-failure batch
+/// operations, keeping every successful response alongside per-query errors.
+use crate::search::{query::SearchQuery, response::SearchResponse};
+use std::time::Duration;
+
+/// Outcome of a single query within a batch
+#[derive(Debug)]
+pub struct BatchItem {
+    /// Position of the query in the input slice
+    pub index: usize,
+    /// The query that was executed
+    pub query: SearchQuery,
+    /// The response, or the error for this query only
+    pub result: Result<SearchResponse>,
+    /// Time spent executing this query (excluding time waiting for a slot)
+    pub elapsed: Duration,
+}
+
+impl BatchItem {
+    /// Checks if this query succeeded
+    pub fn is_success(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+/// Report of a batch of searches where individual queries may fail
+///
+/// Items are kept in the same order as the input queries, so a job with a
+/// handful of failures keeps every other response.
+#[derive(Debug)]
+pub struct BatchReport {
+    /// Per-query outcomes, in input order
+    pub items: Vec<BatchItem>,
+    /// Wall-clock time for the whole batch
+    pub elapsed: Duration,
+}
+
+impl BatchReport {
+    /// Creates a report from per-query outcomes, sorting them by input index
+    pub fn new(mut items: Vec<BatchItem>, elapsed: Duration) -> Self {
+        items.sort_by_key(|item| item.index);
+        Self { items, elapsed }
+    }
+
+    /// Gets the total number of queries in the batch
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Checks if the batch contained no queries
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Gets the number of successful queries
+    pub fn success_count(&self) -> usize {
+        self.items.iter().filter(|item| item.is_success()).count()
+    }
+
+    /// Gets the number of failed queries
+    pub fn failure_count(&self) -> usize {
+        self.len() - self.success_count()
+    }
+
+    /// Checks if every query succeeded
+    pub fn all_succeeded(&self) -> bool {
+        self.items.iter().all(BatchItem::is_success)
+    }
+
+    /// Iterates over successful queries and their responses
+    pub fn successes(&self) -> impl Iterator<Item = (&SearchQuery, &SearchResponse)> {
+        self.items
+            .iter()
+            .filter_map(|item| item.result.as_ref().ok().map(|r| (&item.query, r)))
+    }
+
+    /// Iterates over failed queries and their errors
+    pub fn failures(&self) -> impl Iterator<Item = (&SearchQuery, &SerperError)> {
+        self.items
+            .iter()
+            .filter_map(|item| item.result.as_ref().err().map(|e| (&item.query, e)))
+    }
+
+    /// Gets the mean time spent per query
+    pub fn average_latency(&self) -> Option<Duration> {
+        let count = u32::try_from(self.items.len()).ok().filter(|&n| n > 0)?;
+        let total: Duration = self.items.iter().map(|item| item.elapsed).sum();
+        Some(total / count)
+    }
+
+    /// Consumes the report, returning `(query, result)` pairs in input order
+    pub fn into_results(self) -> Vec<(SearchQuery, Result<SearchResponse>)> {
+        self.items
+            .into_iter()
+            .map(|item| (item.query, item.result))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(index: usize, result: Result<SearchResponse>, millis: u64) -> BatchItem {
+        BatchItem {
+            index,
+            query: SearchQuery::new(format!("query {}", index)).unwrap(),
+            result,
+            elapsed: Duration::from_millis(millis),
+        }
+    }
+
+    #[test]
+    fn test_batch_report_counts() {
+        let report = BatchReport::new(
+            vec![
+                item(2, Ok(SearchResponse::new()), 30),
+                item(0, Ok(SearchResponse::new()), 10),
+                item(1, Err(SerperError::api_error("HTTP 500")), 20),
+            ],
+            Duration::from_millis(40),
+        );
+
+        assert_eq!(report.len(), 3);
+        assert_eq!(report.success_count(), 2);
+        assert_eq!(report.failure_count(), 1);
+        assert!(!report.all_succeeded());
+        assert_eq!(report.average_latency(), Some(Duration::from_millis(20)));
+
+        let failed: Vec<&str> = report.failures().map(|(query, _)| query.query()).collect();
+        assert_eq!(failed, vec!["query 1"]);
+
+        let results = report.into_results();
+        assert_eq!(results[0].0.query(), "query 0");
+        assert_eq!(results[2].0.query(), "query 2");
+    }
+
+    #[test]
+    fn test_empty_batch_report() {
+        let report = BatchReport::new(Vec::new(), Duration::ZERO);
+        assert!(report.is_empty());
+        assert!(report.all_succeeded());
+        assert_eq!(report.average_latency(), None);
+    }
+}
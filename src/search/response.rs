@@ -2,14 +2,14 @@
 ///
 /// This module provides data structures and utilities for handling search responses
 /// from the Serper API, including organic results, answer boxes, and knowledge graphs.
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Complete search response from the Serper API
 ///
 /// This struct represents the full response structure that can be returned
 /// by the Serper search API, with all possible fields as optional.
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct SearchResponse {
     /// Metadata about the search request and response
     pub search_metadata: Option<SearchMetadata>,
@@ -31,6 +31,9 @@ pub struct SearchResponse {
 
     /// News results (if applicable)
     pub news: Option<Vec<NewsResult>>,
+
+    /// Credits charged for the request, as reported by the API
+    pub credits: Option<u32>,
 }
 
 impl SearchResponse {
@@ -44,6 +47,7 @@ impl SearchResponse {
             related_questions: None,
             shopping: None,
             news: None,
+            credits: None,
         }
     }
 
@@ -87,7 +91,7 @@ impl Default for SearchResponse {
 }
 
 /// Metadata about the search request and response
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct SearchMetadata {
     /// Unique identifier for this search
     pub id: String,
@@ -106,7 +110,7 @@ pub struct SearchMetadata {
 }
 
 /// Individual organic search result
-#[derive(Debug, Deserialize, PartialEq, Clone)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct OrganicResult {
     /// Title of the search result
     pub title: String,
@@ -156,10 +160,18 @@ impl OrganicResult {
             .host_str()
             .map(|host| host.to_string())
     }
+
+    /// Gets the URL normalized for duplicate detection
+    ///
+    /// See [`crate::utils::url::normalize_url`]; returns `None` if the link is
+    /// not a valid URL.
+    pub fn normalized_link(&self) -> Option<String> {
+        crate::utils::url::normalize_url(&self.link).ok()
+    }
 }
 
 /// Answer box with direct answers to queries
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct AnswerBox {
     /// Direct answer text (optional)
     pub answer: Option<String>,
@@ -187,7 +199,7 @@ impl AnswerBox {
 }
 
 /// Knowledge graph information
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct KnowledgeGraph {
     /// Title of the entity
     pub title: Option<String>,
@@ -208,7 +220,7 @@ pub struct KnowledgeGraph {
 }
 
 /// Related question from "People also ask"
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct RelatedQuestion {
     /// The question text
     pub question: String,
@@ -224,7 +236,7 @@ pub struct RelatedQuestion {
 }
 
 /// Shopping result for product searches
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct ShoppingResult {
     /// Product title
     pub title: String,
@@ -246,7 +258,7 @@ pub struct ShoppingResult {
 }
 
 /// News result for news searches
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct NewsResult {
     /// News article title
     pub title: String,
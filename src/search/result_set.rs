@@ -0,0 +1,216 @@
+use crate::search::{
+    query::SearchQuery,
+    response::{OrganicResult, SearchResponse},
+};
+/// Result deduplication module
+///
+/// This module provides a collector that merges organic results from many
+/// pages or queries and removes duplicate URLs.
+use std::collections::HashMap;
+
+/// An organic result kept by a [`ResultSet`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RankedResult {
+    /// The result with the best position seen for its URL
+    pub result: OrganicResult,
+    /// The query that produced the best position
+    pub source_query: String,
+    /// Number of times the URL was seen, including the kept result
+    pub occurrences: usize,
+}
+
+/// Collector that merges organic results and drops duplicate URLs
+///
+/// URLs are compared after normalization (scheme, `www.`, trailing slash and
+/// tracking parameters are ignored, see [`crate::utils::url::normalize_url`]).
+/// When a URL is seen again with a better (lower) position, that result and
+/// its source query replace the earlier one.
+///
+/// # Example
+///
+/// ```rust
+/// use serper_sdk::search::{OrganicResult, ResultSet};
+///
+/// let mut set = ResultSet::new();
+/// set.add_result("rust", OrganicResult::new("Rust".into(), "https://www.rust-lang.org/".into(), 3));
+/// set.add_result("rust lang", OrganicResult::new("Rust".into(), "http://rust-lang.org".into(), 1));
+///
+/// assert_eq!(set.len(), 1);
+/// assert_eq!(set.duplicates(), 1);
+/// let best = set.iter().next().unwrap();
+/// assert_eq!(best.result.position, 1);
+/// assert_eq!(best.source_query, "rust lang");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ResultSet {
+    entries: Vec<RankedResult>,
+    index: HashMap<String, usize>,
+    duplicates: usize,
+}
+
+impl ResultSet {
+    /// Creates an empty result set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the organic results of a response
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The query that produced the response
+    /// * `response` - The response to merge
+    pub fn add_response(&mut self, query: &SearchQuery, response: &SearchResponse) {
+        for result in response.organic_results() {
+            self.add_result(query.query(), result.clone());
+        }
+    }
+
+    /// Adds a single organic result
+    ///
+    /// # Arguments
+    ///
+    /// * `source_query` - The query that produced the result
+    /// * `result` - The result to merge
+    ///
+    /// # Returns
+    ///
+    /// True if the URL had not been seen before
+    pub fn add_result(&mut self, source_query: impl Into<String>, result: OrganicResult) -> bool {
+        let key = result
+            .normalized_link()
+            .unwrap_or_else(|| result.link.trim().to_string());
+
+        match self.index.get(&key) {
+            Some(&position) => {
+                self.duplicates += 1;
+                let entry = &mut self.entries[position];
+                entry.occurrences += 1;
+                if result.position < entry.result.position {
+                    entry.result = result;
+                    entry.source_query = source_query.into();
+                }
+                false
+            }
+            None => {
+                self.index.insert(key, self.entries.len());
+                self.entries.push(RankedResult {
+                    result,
+                    source_query: source_query.into(),
+                    occurrences: 1,
+                });
+                true
+            }
+        }
+    }
+
+    /// Gets the number of unique URLs
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Checks if no results have been added
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Gets the number of duplicate results that were merged away
+    pub fn duplicates(&self) -> usize {
+        self.duplicates
+    }
+
+    /// Iterates over unique results in the order their URLs were first seen
+    pub fn iter(&self) -> impl Iterator<Item = &RankedResult> {
+        self.entries.iter()
+    }
+
+    /// Gets the distinct domains of the results, in first-seen order
+    pub fn domains(&self) -> Vec<String> {
+        let mut domains: Vec<String> = Vec::new();
+        for domain in self
+            .entries
+            .iter()
+            .filter_map(|entry| entry.result.domain())
+        {
+            if !domains.contains(&domain) {
+                domains.push(domain);
+            }
+        }
+        domains
+    }
+
+    /// Consumes the set, returning unique results sorted by best position
+    ///
+    /// Results with equal positions keep their first-seen order.
+    pub fn into_results(self) -> Vec<RankedResult> {
+        let mut entries = self.entries;
+        entries.sort_by_key(|entry| entry.result.position);
+        entries
+    }
+}
+
+impl Extend<(String, OrganicResult)> for ResultSet {
+    fn extend<I: IntoIterator<Item = (String, OrganicResult)>>(&mut self, iter: I) {
+        for (source_query, result) in iter {
+            self.add_result(source_query, result);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(link: &str, position: u32) -> OrganicResult {
+        OrganicResult::new(format!("Title {}", position), link.to_string(), position)
+    }
+
+    #[test]
+    fn test_dedupes_normalized_urls() {
+        let mut set = ResultSet::new();
+        assert!(set.add_result("q", result("https://example.com/page", 4)));
+        assert!(!set.add_result("q", result("http://www.example.com/page/", 9)));
+        assert!(!set.add_result("q", result("https://example.com/page?utm_source=x", 7)));
+        assert!(set.add_result("q", result("https://example.com/other", 5)));
+
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.duplicates(), 2);
+
+        let first = set.iter().next().unwrap();
+        assert_eq!(first.result.position, 4);
+        assert_eq!(first.occurrences, 3);
+    }
+
+    #[test]
+    fn test_keeps_best_position_and_source() {
+        let mut set = ResultSet::new();
+        set.add_result("page two", result("https://a.com", 12));
+        set.add_result("page one", result("https://b.com", 2));
+        set.add_result("other query", result("https://www.a.com/", 1));
+
+        let results = set.into_results();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].result.link, "https://www.a.com/");
+        assert_eq!(results[0].source_query, "other query");
+        assert_eq!(results[1].result.position, 2);
+    }
+
+    #[test]
+    fn test_add_response_and_domains() {
+        let query = SearchQuery::new("rust".to_string()).unwrap();
+        let mut response = SearchResponse::new();
+        response.organic = Some(vec![
+            result("https://docs.rs/tokio", 1),
+            result("https://docs.rs/serde", 2),
+            result("not a url", 3),
+            result("not a url", 4),
+        ]);
+
+        let mut set = ResultSet::new();
+        set.add_response(&query, &response);
+
+        assert_eq!(set.len(), 3);
+        assert_eq!(set.iter().next().unwrap().source_query, "rust");
+        assert_eq!(set.domains(), vec!["docs.rs".to_string()]);
+    }
+}
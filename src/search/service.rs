@@ -1,19 +1,33 @@
+#[cfg(feature = "disk-cache")]
+use crate::search::DiskCache;
 /// Search service orchestration module
 ///
 /// This module provides the main search service that orchestrates
 /// query building, HTTP requests, and response processing.
 use crate::{
     core::{Result, types::ApiKey, types::BaseUrl},
-    http::{SerperHttpClient, TransportConfig},
-    search::{SearchQuery, SearchQueryBuilder, SearchResponse},
+    http::{
+        ApiKeyPool, CircuitBreakerConfig, CircuitState, CreditBudget, HedgeConfig, HedgeStats,
+        KeyStrategy, MAX_BATCH_SIZE, Middleware, RateLimiter, Request, RequestOptions, Response,
+        SerperHttpClient, SerperHttpClientBuilder, Transport, TransportConfig,
+    },
+    search::{
+        BatchReport, CacheConfig, OrganicResult, PaginationLimits, ResponseCache, SearchQuery,
+        SearchQueryBuilder, SearchResponse, paginate,
+    },
+    utils::retry::RetryConfig,
 };
+use futures::Stream;
+use std::sync::Arc;
 use std::time::Duration;
 
 /// Main search service for the Serper SDK
 ///
 /// This service provides the primary interface for search operations,
 /// combining query building, HTTP client management, and response processing.
-#[derive(Debug)]
+///
+/// Cloning is cheap and clones share one connection pool.
+#[derive(Debug, Clone)]
 pub struct SearchService {
     http_client: SerperHttpClient,
 }
@@ -67,6 +81,69 @@ impl SearchService {
         self.http_client.search(query).await
     }
 
+    /// Performs a search, accepting cached responses up to `max_age` old
+    ///
+    /// `max_age` overrides the TTLs of the in-memory and disk caches for this
+    /// call; `Duration::ZERO` always sends the request and refreshes them.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The search query to execute
+    /// * `max_age` - Maximum age of an acceptable cached response
+    ///
+    /// # Returns
+    ///
+    /// Result containing the search response or an error
+    pub async fn search_with_max_age(
+        &self,
+        query: &SearchQuery,
+        max_age: Duration,
+    ) -> Result<SearchResponse> {
+        self.http_client.search_with_max_age(query, max_age).await
+    }
+
+    /// Performs a search with a per-call timeout and cancellation token
+    ///
+    /// Interactive callers can use a short deadline without changing the
+    /// service's configured timeout.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The search query to execute
+    /// * `options` - Timeout and cancellation token for this call
+    ///
+    /// # Returns
+    ///
+    /// Result containing the search response, or `SerperError::Cancelled` if
+    /// the token fired first
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use serper_sdk::http::{CancellationToken, RequestOptions};
+    /// use serper_sdk::{SearchQuery, SearchService};
+    /// use std::time::Duration;
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service = SearchService::new("your-api-key".to_string())?;
+    /// let cancel = CancellationToken::new();
+    /// let options = RequestOptions::new()
+    ///     .with_timeout(Duration::from_secs(2))
+    ///     .with_cancel(cancel.clone());
+    ///
+    /// let query = SearchQuery::new("rust".to_string())?;
+    /// let response = service.search_with_options(&query, options).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_with_options(
+        &self,
+        query: &SearchQuery,
+        options: RequestOptions,
+    ) -> Result<SearchResponse> {
+        self.http_client.search_with_options(query, options).await
+    }
+
     /// Performs a search with a simple query string
     ///
     /// # Arguments
@@ -94,6 +171,52 @@ impl SearchService {
         self.http_client.search_multiple(queries).await
     }
 
+    /// Performs multiple searches using Serper's native batch requests
+    ///
+    /// # Arguments
+    ///
+    /// * `queries` - The search queries to execute
+    /// * `batch_size` - Maximum number of queries per request (default and maximum: 100)
+    ///
+    /// # Returns
+    ///
+    /// One result per query, in the same order as `queries`
+    pub async fn search_batch(
+        &self,
+        queries: &[SearchQuery],
+        batch_size: Option<usize>,
+    ) -> Vec<Result<SearchResponse>> {
+        let batch_size = batch_size.unwrap_or(MAX_BATCH_SIZE);
+        self.http_client.search_batch(queries, batch_size).await
+    }
+
+    /// Performs batch searches with a per-call timeout and cancellation token
+    ///
+    /// When the token fires, the chunk in flight and the chunks not yet sent
+    /// fail with `SerperError::Cancelled`; completed chunks keep their
+    /// results.
+    ///
+    /// # Arguments
+    ///
+    /// * `queries` - The search queries to execute
+    /// * `batch_size` - Maximum number of queries per request (default and maximum: 100)
+    /// * `options` - Timeout and cancellation token for this call
+    ///
+    /// # Returns
+    ///
+    /// One result per query, in the same order as `queries`
+    pub async fn search_batch_with_options(
+        &self,
+        queries: &[SearchQuery],
+        batch_size: Option<usize>,
+        options: RequestOptions,
+    ) -> Vec<Result<SearchResponse>> {
+        let batch_size = batch_size.unwrap_or(MAX_BATCH_SIZE);
+        self.http_client
+            .search_batch_with_options(queries, batch_size, options)
+            .await
+    }
+
     /// Performs multiple searches concurrently
     ///
     /// # Arguments
@@ -115,6 +238,162 @@ impl SearchService {
             .await
     }
 
+    /// Performs multiple searches concurrently, yielding results as they complete
+    ///
+    /// Useful for writing results incrementally or reporting progress on
+    /// large keyword sets. Items arrive in completion order and carry the
+    /// index of their query in `queries`.
+    ///
+    /// # Arguments
+    ///
+    /// * `queries` - The search queries to execute
+    /// * `max_concurrent` - Maximum number of requests in flight (default: 5)
+    ///
+    /// # Returns
+    ///
+    /// A stream of `(index, result)` pairs, one per query
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures::StreamExt;
+    /// use serper_sdk::{SearchQuery, SearchService};
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service = SearchService::new("your-api-key".to_string())?;
+    /// let queries = vec![
+    ///     SearchQuery::new("rust async".to_string())?,
+    ///     SearchQuery::new("rust streams".to_string())?,
+    /// ];
+    ///
+    /// let mut results = service.search_stream(&queries, Some(10));
+    /// while let Some((index, result)) = results.next().await {
+    ///     match result {
+    ///         Ok(response) => println!("{}: {} results", queries[index].q, response.organic_count()),
+    ///         Err(e) => eprintln!("{} failed: {}", queries[index].q, e),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn search_stream<'a>(
+        &'a self,
+        queries: &'a [SearchQuery],
+        max_concurrent: Option<usize>,
+    ) -> impl Stream<Item = (usize, Result<SearchResponse>)> + 'a {
+        let max_concurrent = max_concurrent.unwrap_or(5);
+        self.http_client.search_stream(queries, max_concurrent)
+    }
+
+    /// Performs multiple searches concurrently, keeping partial results
+    ///
+    /// Failed queries are reported individually instead of discarding the
+    /// responses that did succeed.
+    ///
+    /// # Arguments
+    ///
+    /// * `queries` - The search queries to execute
+    /// * `max_concurrent` - Maximum number of concurrent requests (default: 5)
+    ///
+    /// # Returns
+    ///
+    /// A report with per-query results and timing, in input order
+    pub async fn search_concurrent_report(
+        &self,
+        queries: &[SearchQuery],
+        max_concurrent: Option<usize>,
+    ) -> BatchReport {
+        let max_concurrent = max_concurrent.unwrap_or(5);
+        self.http_client
+            .search_concurrent_report(queries, max_concurrent)
+            .await
+    }
+
+    /// Performs concurrent searches with a per-call timeout and cancellation token
+    ///
+    /// When the token fires, queries in flight or still queued fail with
+    /// `SerperError::Cancelled`; completed queries keep their results.
+    ///
+    /// # Arguments
+    ///
+    /// * `queries` - The search queries to execute
+    /// * `max_concurrent` - Maximum number of concurrent requests (default: 5)
+    /// * `options` - Timeout and cancellation token for this call
+    ///
+    /// # Returns
+    ///
+    /// A report with per-query results and timing, in input order
+    pub async fn search_concurrent_report_with_options(
+        &self,
+        queries: &[SearchQuery],
+        max_concurrent: Option<usize>,
+        options: RequestOptions,
+    ) -> BatchReport {
+        let max_concurrent = max_concurrent.unwrap_or(5);
+        self.http_client
+            .search_concurrent_report_with_options(queries, max_concurrent, options)
+            .await
+    }
+
+    /// Walks the result pages of a query, yielding organic results
+    ///
+    /// Pages are requested one at a time starting at `query.page` (or page 1)
+    /// until a page comes back empty or the default limit of
+    /// [`DEFAULT_MAX_PAGES`](crate::search::paginate::DEFAULT_MAX_PAGES) pages
+    /// is reached. Result positions are renumbered from 1 across all pages.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The search query to paginate
+    ///
+    /// # Returns
+    ///
+    /// A stream of organic results; an error ends the stream
+    pub fn paginate(
+        &self,
+        query: &SearchQuery,
+    ) -> impl Stream<Item = Result<OrganicResult>> + 'static {
+        self.paginate_with_limits(query, PaginationLimits::default())
+    }
+
+    /// Walks the result pages of a query with custom limits
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The search query to paginate
+    /// * `limits` - Maximum number of results and/or pages to fetch
+    ///
+    /// # Returns
+    ///
+    /// A stream of organic results; an error ends the stream
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures::TryStreamExt;
+    /// use serper_sdk::{SearchQuery, SearchService};
+    /// use serper_sdk::search::PaginationLimits;
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service = SearchService::new("your-api-key".to_string())?;
+    /// let query = SearchQuery::new("rust web frameworks".to_string())?;
+    ///
+    /// let top_100: Vec<_> = service
+    ///     .paginate_with_limits(&query, PaginationLimits::unlimited().with_max_results(100))
+    ///     .try_collect()
+    ///     .await?;
+    /// println!("Collected {} results", top_100.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn paginate_with_limits(
+        &self,
+        query: &SearchQuery,
+        limits: PaginationLimits,
+    ) -> impl Stream<Item = Result<OrganicResult>> + 'static {
+        paginate::paginate(self.http_client.clone(), query.clone(), limits)
+    }
+
     /// Creates a new query builder
     ///
     /// # Returns
@@ -162,6 +441,46 @@ impl SearchService {
         self.search(&query).await
     }
 
+    /// Gets the response cache, if caching is enabled
+    ///
+    /// Use it to read statistics or invalidate entries.
+    pub fn cache(&self) -> Option<&ResponseCache> {
+        self.http_client.cache()
+    }
+
+    /// Gets the persistent response cache, if enabled
+    #[cfg(feature = "disk-cache")]
+    pub fn disk_cache(&self) -> Option<&DiskCache> {
+        self.http_client.disk_cache()
+    }
+
+    /// Checks if concurrent identical searches share one request
+    pub fn coalesces_requests(&self) -> bool {
+        self.http_client.coalesces_requests()
+    }
+
+    /// Gets the hedging counters, if hedging is enabled
+    pub fn hedge_stats(&self) -> Option<HedgeStats> {
+        self.http_client.hedge_stats()
+    }
+
+    /// Gets the API key pool, if one is configured
+    ///
+    /// Use it to read per-key usage or restore exhausted keys.
+    pub fn key_pool(&self) -> Option<&ApiKeyPool> {
+        self.http_client.key_pool()
+    }
+
+    /// Gets the credit budget, if one is configured
+    pub fn credit_budget(&self) -> Option<&Arc<CreditBudget>> {
+        self.http_client.credit_budget()
+    }
+
+    /// Gets the circuit breaker state, if a circuit breaker is enabled
+    pub fn circuit_state(&self) -> Option<CircuitState> {
+        self.http_client.circuit_state()
+    }
+
     /// Gets information about the current service configuration
     pub fn info(&self) -> SearchServiceInfo {
         SearchServiceInfo {
@@ -186,8 +505,9 @@ pub struct SearchServiceInfo {
 /// Builder for creating search services with custom configuration
 pub struct SearchServiceBuilder {
     api_key: Option<String>,
+    api_keys: Option<(Vec<String>, KeyStrategy)>,
     base_url: Option<String>,
-    transport_config: TransportConfig,
+    client: SerperHttpClientBuilder,
 }
 
 impl SearchServiceBuilder {
@@ -195,8 +515,9 @@ impl SearchServiceBuilder {
     pub fn new() -> Self {
         Self {
             api_key: None,
+            api_keys: None,
             base_url: None,
-            transport_config: TransportConfig::new(),
+            client: SerperHttpClientBuilder::new(),
         }
     }
 
@@ -206,6 +527,25 @@ impl SearchServiceBuilder {
         self
     }
 
+    /// Spreads requests over several API keys
+    ///
+    /// A key rejected by Serper (HTTP 401/403) or reported out of credits is
+    /// taken out of rotation and the request is retried with the next key.
+    /// The pool's usage is available from [`SearchService::key_pool`].
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The API keys, in failover order
+    /// * `strategy` - How keys are picked for each request
+    pub fn api_keys<I, K>(mut self, keys: I, strategy: KeyStrategy) -> Self
+    where
+        I: IntoIterator<Item = K>,
+        K: Into<String>,
+    {
+        self.api_keys = Some((keys.into_iter().map(Into::into).collect(), strategy));
+        self
+    }
+
     /// Sets the base URL
     pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
         self.base_url = Some(base_url.into());
@@ -214,40 +554,150 @@ impl SearchServiceBuilder {
 
     /// Sets the request timeout
     pub fn timeout(mut self, timeout: Duration) -> Self {
-        self.transport_config = self.transport_config.with_timeout(timeout);
+        self.client = self.client.timeout(timeout);
         self
     }
 
     /// Adds a default header
     pub fn header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
-        self.transport_config = self.transport_config.with_header(key.into(), value.into());
+        self.client = self.client.header(key, value);
         self
     }
 
     /// Sets the user agent
     pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
-        self.transport_config = self.transport_config.with_user_agent(user_agent.into());
+        self.client = self.client.user_agent(user_agent);
+        self
+    }
+
+    /// Enables retries of transient failures (connect errors, timeouts, 429 and 5xx)
+    pub fn retry(mut self, retry: RetryConfig) -> Self {
+        self.client = self.client.retry(retry);
+        self
+    }
+
+    /// Sets a client-side rate limiter
+    ///
+    /// Pass clones of the same `Arc` to several builders to share one
+    /// requests-per-second budget between services.
+    pub fn rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.client = self.client.rate_limiter(rate_limiter);
+        self
+    }
+
+    /// Enables a circuit breaker that fails fast with `SerperError::CircuitOpen`
+    /// after repeated transient failures
+    pub fn circuit_breaker(mut self, circuit_breaker: CircuitBreakerConfig) -> Self {
+        self.client = self.client.circuit_breaker(circuit_breaker);
+        self
+    }
+
+    /// Sends requests through a custom transport, e.g. a mock in unit tests
+    ///
+    /// Timeout, header, retry, rate limiter and circuit breaker settings still
+    /// apply around the custom transport; the user agent is left to it.
+    pub fn transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.client = self.client.transport(transport);
+        self
+    }
+
+    /// Adds a middleware to the request chain
+    ///
+    /// Middleware runs in the order it is added before each request, and in
+    /// reverse order after each response.
+    pub fn middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.client = self.client.middleware(middleware);
+        self
+    }
+
+    /// Adds a hook that can modify each request before it is sent, e.g. to
+    /// add a correlation id or a signature
+    pub fn before_request<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&mut Request) -> Result<()> + Send + Sync + 'static,
+    {
+        self.client = self.client.before_request(hook);
+        self
+    }
+
+    /// Adds a hook that sees each successful response and how long it took
+    pub fn after_response<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&Request, &mut Response, Duration) -> Result<()> + Send + Sync + 'static,
+    {
+        self.client = self.client.after_response(hook);
+        self
+    }
+
+    /// Enables an in-memory response cache
+    ///
+    /// Equal queries (see [`crate::search::cache::cache_key`]) are answered
+    /// from the cache until the TTL expires, without spending credits.
+    pub fn cache(mut self, cache: CacheConfig) -> Self {
+        self.client = self.client.cache(cache);
+        self
+    }
+
+    /// Enables a persistent response cache that survives restarts
+    ///
+    /// It is consulted after the in-memory cache, if both are enabled.
+    #[cfg(feature = "disk-cache")]
+    pub fn disk_cache(mut self, disk_cache: DiskCache) -> Self {
+        self.client = self.client.disk_cache(disk_cache);
+        self
+    }
+
+    /// Sets whether concurrent identical searches share one request
+    ///
+    /// Disabled by default. When enabled, tasks calling
+    /// [`SearchService::search`] with identical queries while one of them is
+    /// in flight all receive that request's response or error, spending a
+    /// single credit.
+    pub fn coalesce_requests(mut self, enabled: bool) -> Self {
+        self.client = self.client.coalesce_requests(enabled);
+        self
+    }
+
+    /// Caps the credits this service may spend
+    ///
+    /// Share one budget with `Arc` to cap the whole process, or give each
+    /// job its own. Once it is used up, searches that need a request fail
+    /// with `SerperError::BudgetExceeded`; cached responses are still served.
+    pub fn credit_budget(mut self, credit_budget: Arc<CreditBudget>) -> Self {
+        self.client = self.client.credit_budget(credit_budget);
+        self
+    }
+
+    /// Enables hedged searches for lower tail latency
+    ///
+    /// A search still unanswered after the configured latency percentile of
+    /// recent searches is sent a second time and the first successful copy
+    /// wins. Each hedge may cost an extra credit, so hedges are capped per
+    /// minute by [`HedgeConfig::max_hedges_per_minute`].
+    pub fn hedging(mut self, hedging: HedgeConfig) -> Self {
+        self.client = self.client.hedging(hedging);
         self
     }
 
     /// Builds the search service
     pub fn build(self) -> Result<SearchService> {
-        let api_key = self
-            .api_key
-            .ok_or_else(|| crate::core::SerperError::config_error("API key is required"))?;
-
-        match self.base_url {
-            Some(base_url) => SearchService::with_config(api_key, base_url, self.transport_config),
-            None => {
-                let api_key_obj = ApiKey::new(api_key)?;
-                let http_client = SerperHttpClient::with_config(
-                    api_key_obj,
-                    BaseUrl::default(),
-                    self.transport_config,
-                )?;
-                Ok(SearchService { http_client })
-            }
+        let mut client = self.client;
+        if let Some((keys, strategy)) = self.api_keys {
+            let keys = keys.into_iter().map(ApiKey::new).collect::<Result<_>>()?;
+            client = client.api_key_pool(ApiKeyPool::new(keys, strategy)?);
+        } else {
+            let api_key = self
+                .api_key
+                .ok_or_else(|| crate::core::SerperError::config_error("API key is required"))?;
+            client = client.api_key(ApiKey::new(api_key)?);
+        }
+        if let Some(base_url) = self.base_url {
+            client = client.base_url(BaseUrl::new(base_url));
         }
+
+        Ok(SearchService {
+            http_client: client.build()?,
+        })
     }
 }
 
@@ -0,0 +1,400 @@
+use crate::core::{
+    error::{Result, SerperError},
+    types::{Location, Pagination},
+};
+/// Query template expansion module
+///
+/// This module provides templates such as `"best {product} in {city}"` that
+/// expand a set of variables into many search queries for bulk keyword jobs.
+use crate::search::query::SearchQuery;
+
+/// How template variables are combined during expansion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExpansionMode {
+    /// Every combination of variable values (cartesian product)
+    #[default]
+    Cartesian,
+    /// Values at the same index are used together; all variables must have
+    /// the same number of values
+    Zip,
+}
+
+/// Template for generating search queries from variable substitutions
+///
+/// Placeholders are written as `{name}` and are replaced by the values
+/// registered with [`QueryTemplate::with_variable`].
+///
+/// # Example
+///
+/// ```rust
+/// use serper_sdk::search::QueryTemplate;
+/// use serper_sdk::core::Location;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let queries = QueryTemplate::new("best {product} in {city}")?
+///         .with_variable("product", ["pizza", "sushi"])
+///         .with_variable("city", ["Paris", "Lyon"])
+///         .with_location(Location::new().with_country("fr"))
+///         .expand()?;
+///
+///     assert_eq!(queries.len(), 4);
+///     assert_eq!(queries[0].q, "best pizza in Paris");
+///     // In real usage: service.search_concurrent(&queries, Some(10)).await?
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryTemplate {
+    pattern: String,
+    variables: Vec<(String, Vec<String>)>,
+    mode: ExpansionMode,
+    location: Option<Location>,
+    pagination: Option<Pagination>,
+}
+
+impl QueryTemplate {
+    /// Creates a new query template
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The query pattern with `{name}` placeholders
+    ///
+    /// # Returns
+    ///
+    /// Result containing the template or an error if the pattern is empty
+    pub fn new(pattern: impl Into<String>) -> Result<Self> {
+        let pattern = pattern.into();
+        if pattern.trim().is_empty() {
+            return Err(SerperError::validation_error(
+                "Template pattern cannot be empty",
+            ));
+        }
+
+        Ok(Self {
+            pattern,
+            variables: Vec::new(),
+            mode: ExpansionMode::default(),
+            location: None,
+            pagination: None,
+        })
+    }
+
+    /// Sets the values for a placeholder, replacing any previous values
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The placeholder name (without braces)
+    /// * `values` - The values to substitute
+    pub fn with_variable<I, V>(mut self, name: impl Into<String>, values: I) -> Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        let name = name.into();
+        let values = values.into_iter().map(Into::into).collect();
+
+        match self
+            .variables
+            .iter_mut()
+            .find(|(existing, _)| *existing == name)
+        {
+            Some((_, existing)) => *existing = values,
+            None => self.variables.push((name, values)),
+        }
+        self
+    }
+
+    /// Sets how variables are combined during expansion
+    pub fn with_mode(mut self, mode: ExpansionMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Combines variables by index instead of as a cartesian product
+    pub fn zipped(self) -> Self {
+        self.with_mode(ExpansionMode::Zip)
+    }
+
+    /// Sets location defaults applied to every generated query
+    pub fn with_location(mut self, location: Location) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    /// Sets pagination defaults applied to every generated query
+    pub fn with_pagination(mut self, pagination: Pagination) -> Self {
+        self.pagination = Some(pagination);
+        self
+    }
+
+    /// Gets the query pattern
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Gets the placeholder names used in the pattern, in order of first use
+    pub fn placeholders(&self) -> Vec<&str> {
+        let mut names = Vec::new();
+        for segment in parse_pattern(&self.pattern) {
+            if let Segment::Placeholder(name) = segment
+                && !names.contains(&name)
+            {
+                names.push(name);
+            }
+        }
+        names
+    }
+
+    /// Gets the number of queries the template expands to
+    pub fn len(&self) -> usize {
+        match self.mode {
+            ExpansionMode::Cartesian => self
+                .variables
+                .iter()
+                .map(|(_, values)| values.len())
+                .product(),
+            ExpansionMode::Zip => self.variables.first().map_or(1, |(_, values)| values.len()),
+        }
+    }
+
+    /// Checks if the template expands to no queries
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Validates that placeholders and variables match up
+    ///
+    /// # Returns
+    ///
+    /// Result indicating whether the template can be expanded
+    pub fn validate(&self) -> Result<()> {
+        let placeholders = self.placeholders();
+
+        for name in &placeholders {
+            if !self.variables.iter().any(|(variable, _)| variable == name) {
+                return Err(SerperError::validation_error(format!(
+                    "Template placeholder '{{{}}}' has no variable",
+                    name
+                )));
+            }
+        }
+
+        for (name, values) in &self.variables {
+            if !placeholders.contains(&name.as_str()) {
+                return Err(SerperError::validation_error(format!(
+                    "Template variable '{}' is not used in the pattern",
+                    name
+                )));
+            }
+            if values.is_empty() {
+                return Err(SerperError::validation_error(format!(
+                    "Template variable '{}' has no values",
+                    name
+                )));
+            }
+        }
+
+        if self.mode == ExpansionMode::Zip
+            && let Some((_, first)) = self.variables.first()
+            && let Some((name, values)) = self
+                .variables
+                .iter()
+                .find(|(_, values)| values.len() != first.len())
+        {
+            return Err(SerperError::validation_error(format!(
+                "Zipped template variable '{}' has {} values, expected {}",
+                name,
+                values.len(),
+                first.len()
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Expands the template into search queries
+    ///
+    /// Queries are generated in a stable order: for cartesian expansion the
+    /// last registered variable changes fastest.
+    ///
+    /// # Returns
+    ///
+    /// Result containing the generated queries or a validation error
+    pub fn expand(&self) -> Result<Vec<SearchQuery>> {
+        self.validate()?;
+
+        let segments = parse_pattern(&self.pattern);
+        let mut queries = Vec::with_capacity(self.len());
+
+        for index in 0..self.len() {
+            let q = segments
+                .iter()
+                .map(|segment| match segment {
+                    Segment::Literal(text) => *text,
+                    Segment::Placeholder(name) => self.value_at(name, index),
+                })
+                .collect::<String>();
+
+            let mut query = SearchQuery::new(q)?;
+            if let Some(location) = &self.location {
+                query = query.with_location_config(location.clone());
+            }
+            if let Some(pagination) = &self.pagination {
+                query = query.with_pagination(pagination.clone());
+            }
+            query.validate()?;
+            queries.push(query);
+        }
+
+        Ok(queries)
+    }
+
+    /// Gets the value of a variable for the combination at `index`
+    fn value_at(&self, name: &str, index: usize) -> &str {
+        let position = self
+            .variables
+            .iter()
+            .position(|(variable, _)| variable == name)
+            .expect("template was validated");
+
+        let value_index = match self.mode {
+            ExpansionMode::Zip => index,
+            ExpansionMode::Cartesian => {
+                let stride: usize = self.variables[position + 1..]
+                    .iter()
+                    .map(|(_, values)| values.len())
+                    .product();
+                (index / stride) % self.variables[position].1.len()
+            }
+        };
+
+        &self.variables[position].1[value_index]
+    }
+}
+
+/// A piece of a parsed template pattern
+#[derive(Debug, PartialEq)]
+enum Segment<'a> {
+    Literal(&'a str),
+    Placeholder(&'a str),
+}
+
+/// Splits a pattern into literal text and `{name}` placeholders
+///
+/// Braces that do not enclose a valid name (letters, digits and underscores)
+/// are kept as literal text.
+fn parse_pattern(pattern: &str) -> Vec<Segment<'_>> {
+    let mut segments = Vec::new();
+    let mut rest = pattern;
+
+    while let Some(open) = rest.find('{') {
+        let after = &rest[open + 1..];
+        let name = after.find('}').map(|close| &after[..close]).filter(|name| {
+            !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+        });
+
+        match name {
+            Some(name) => {
+                if open > 0 {
+                    segments.push(Segment::Literal(&rest[..open]));
+                }
+                segments.push(Segment::Placeholder(name));
+                rest = &after[name.len() + 1..];
+            }
+            None => {
+                segments.push(Segment::Literal(&rest[..=open]));
+                rest = after;
+            }
+        }
+    }
+
+    if !rest.is_empty() {
+        segments.push(Segment::Literal(rest));
+    }
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cartesian_expansion() {
+        let queries = QueryTemplate::new("best {product} in {city}")
+            .unwrap()
+            .with_variable("product", ["pizza", "sushi"])
+            .with_variable("city", ["Paris", "Lyon", "Nice"])
+            .expand()
+            .unwrap();
+
+        let q: Vec<&str> = queries.iter().map(|query| query.query()).collect();
+        assert_eq!(
+            q,
+            vec![
+                "best pizza in Paris",
+                "best pizza in Lyon",
+                "best pizza in Nice",
+                "best sushi in Paris",
+                "best sushi in Lyon",
+                "best sushi in Nice",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_zipped_expansion_with_defaults() {
+        let queries = QueryTemplate::new("{brand} {model} review")
+            .unwrap()
+            .with_variable("brand", ["Apple", "Google"])
+            .with_variable("model", ["iPhone", "Pixel"])
+            .zipped()
+            .with_location(Location::new().with_country("us").with_language("en"))
+            .with_pagination(Pagination::new().with_num_results(20))
+            .expand()
+            .unwrap();
+
+        assert_eq!(queries.len(), 2);
+        assert_eq!(queries[1].q, "Google Pixel review");
+        assert_eq!(queries[1].gl, Some("us".into()));
+        assert_eq!(queries[1].hl, Some("en".into()));
+        assert_eq!(queries[1].num, Some(20));
+    }
+
+    #[test]
+    fn test_template_validation() {
+        let missing = QueryTemplate::new("best {product} in {city}")
+            .unwrap()
+            .with_variable("product", ["pizza"]);
+        assert!(missing.expand().is_err());
+
+        let unused = QueryTemplate::new("best {product}")
+            .unwrap()
+            .with_variable("product", ["pizza"])
+            .with_variable("city", ["Paris"]);
+        assert!(unused.expand().is_err());
+
+        let uneven = QueryTemplate::new("{a} {b}")
+            .unwrap()
+            .with_variable("a", ["1", "2"])
+            .with_variable("b", ["1"])
+            .zipped();
+        assert!(uneven.expand().is_err());
+
+        let invalid_country = QueryTemplate::new("{a}")
+            .unwrap()
+            .with_variable("a", ["x"])
+            .with_location(Location::new().with_country("france"));
+        assert!(invalid_country.expand().is_err());
+
+        assert!(QueryTemplate::new("  ").is_err());
+    }
+
+    #[test]
+    fn test_parse_pattern() {
+        let template = QueryTemplate::new("{a} and {} or {b c} {a}").unwrap();
+        assert_eq!(template.placeholders(), vec!["a"]);
+
+        let queries = template.with_variable("a", ["x"]).expand().unwrap();
+        assert_eq!(queries[0].q, "x and {} or {b c} x");
+    }
+}
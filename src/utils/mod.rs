@@ -67,6 +67,67 @@ pub mod url {
             .map(|host| host.to_string())
             .ok_or_else(|| SerperError::validation_error("URL has no domain"))
     }
+
+    /// Query parameter names and prefixes added by ad and analytics tracking
+    const TRACKING_PARAMS: &[&str] = &[
+        "gclid", "dclid", "fbclid", "msclkid", "yclid", "srsltid", "mc_cid", "mc_eid", "_ga",
+    ];
+
+    /// Checks if a query parameter is a tracking parameter
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The query parameter name
+    ///
+    /// # Returns
+    ///
+    /// True for `utm_*` parameters and common click identifiers such as `gclid`
+    pub fn is_tracking_param(name: &str) -> bool {
+        let name = name.to_ascii_lowercase();
+        name.starts_with("utm_") || TRACKING_PARAMS.contains(&name.as_str())
+    }
+
+    /// Normalizes a URL for duplicate detection
+    ///
+    /// The scheme, a leading `www.`, the fragment, trailing slashes and
+    /// tracking parameters are dropped, and the remaining query parameters
+    /// are sorted, so `https://www.example.com/a/?utm_source=x` and
+    /// `http://example.com/a` both normalize to `example.com/a`.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The URL string to normalize
+    ///
+    /// # Returns
+    ///
+    /// Result containing the normalized URL or an error
+    pub fn normalize_url(url: &str) -> Result<String> {
+        let parsed = Url::parse(url.trim())
+            .map_err(|_| SerperError::validation_error(format!("Invalid URL: {}", url)))?;
+        let domain = extract_domain(parsed.as_str())?;
+
+        let mut normalized = domain.strip_prefix("www.").unwrap_or(&domain).to_string();
+        if let Some(port) = parsed.port() {
+            normalized.push_str(&format!(":{}", port));
+        }
+        normalized.push_str(parsed.path().trim_end_matches('/'));
+
+        let mut params: Vec<(String, String)> = parsed
+            .query_pairs()
+            .filter(|(name, _)| !is_tracking_param(name))
+            .map(|(name, value)| (name.into_owned(), value.into_owned()))
+            .collect();
+        if !params.is_empty() {
+            params.sort();
+            let query = ::url::form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(params)
+                .finish();
+            normalized.push('?');
+            normalized.push_str(&query);
+        }
+
+        Ok(normalized)
+    }
 }
 
 /// String validation and formatting utilities
@@ -216,13 +277,35 @@ pub mod collections {
 /// Retry utilities for handling transient failures
 pub mod retry {
     use super::*;
+    use std::collections::hash_map::RandomState;
+    use std::fmt;
+    use std::hash::{BuildHasher, Hasher};
+    use std::sync::Arc;
     use std::time::Duration;
     use tokio::time::sleep;
 
+    /// Callback invoked before each retry
+    pub type RetryHook = Arc<dyn Fn(&RetryEvent<'_>) + Send + Sync>;
+
+    /// Details of a failed attempt that is about to be retried
+    #[derive(Debug)]
+    pub struct RetryEvent<'a> {
+        /// The attempt that failed (1-based)
+        pub attempt: usize,
+        /// Maximum number of attempts
+        pub max_attempts: usize,
+        /// Time to wait before the next attempt
+        pub delay: Duration,
+        /// The error returned by the failed attempt
+        pub error: &'a SerperError,
+    }
+
     /// Retry configuration
-    #[derive(Debug, Clone)]
+    ///
+    /// Only transient failures are retried (see [`SerperError::is_retryable`]).
+    #[derive(Clone)]
     pub struct RetryConfig {
-        /// Maximum number of retry attempts
+        /// Maximum number of attempts, including the first one
         pub max_attempts: usize,
         /// Initial delay between retries
         pub initial_delay: Duration,
@@ -230,6 +313,10 @@ pub mod retry {
         pub backoff_multiplier: f64,
         /// Maximum delay between retries
         pub max_delay: Duration,
+        /// Fraction of each delay that is randomized (0.0 to 1.0)
+        pub jitter: f64,
+        /// Callback invoked before each retry
+        pub on_retry: Option<RetryHook>,
     }
 
     impl RetryConfig {
@@ -240,6 +327,8 @@ pub mod retry {
                 initial_delay: Duration::from_millis(100),
                 backoff_multiplier: 2.0,
                 max_delay: Duration::from_secs(10),
+                jitter: 0.5,
+                on_retry: None,
             }
         }
 
@@ -254,6 +343,78 @@ pub mod retry {
             self.initial_delay = delay;
             self
         }
+
+        /// Sets the multiplier for exponential backoff
+        pub fn with_backoff_multiplier(mut self, multiplier: f64) -> Self {
+            self.backoff_multiplier = multiplier;
+            self
+        }
+
+        /// Sets the maximum delay between retries
+        pub fn with_max_delay(mut self, delay: Duration) -> Self {
+            self.max_delay = delay;
+            self
+        }
+
+        /// Sets the randomized fraction of each delay (clamped to 0.0..=1.0)
+        pub fn with_jitter(mut self, jitter: f64) -> Self {
+            self.jitter = jitter.clamp(0.0, 1.0);
+            self
+        }
+
+        /// Sets a callback invoked before each retry
+        ///
+        /// # Arguments
+        ///
+        /// * `hook` - Function receiving the failed attempt, its error and the delay
+        pub fn on_retry<F>(mut self, hook: F) -> Self
+        where
+            F: Fn(&RetryEvent<'_>) + Send + Sync + 'static,
+        {
+            self.on_retry = Some(Arc::new(hook));
+            self
+        }
+
+        /// Gets the delay before retrying after the given failed attempt
+        ///
+        /// # Arguments
+        ///
+        /// * `attempt` - The attempt that failed (1-based)
+        ///
+        /// # Returns
+        ///
+        /// The exponential backoff delay, capped at `max_delay`, with jitter applied
+        pub fn delay_for(&self, attempt: usize) -> Duration {
+            let exponent = attempt.saturating_sub(1).min(i32::MAX as usize) as i32;
+            let backoff = self.initial_delay.as_secs_f64() * self.backoff_multiplier.powi(exponent);
+            let capped = backoff.min(self.max_delay.as_secs_f64());
+            let jittered = capped * (1.0 - self.jitter * random_fraction());
+            Duration::from_secs_f64(jittered.max(0.0))
+        }
+
+        /// Gets the delay before retrying after a failed attempt
+        ///
+        /// A wait advised by the API (`Retry-After` on HTTP 429) takes
+        /// precedence over the backoff delay, but is still capped at
+        /// `max_delay` so a misbehaving server cannot stall the caller.
+        ///
+        /// # Arguments
+        ///
+        /// * `attempt` - The attempt that failed (1-based)
+        /// * `error` - The error returned by the failed attempt
+        pub fn delay_after(&self, attempt: usize, error: &SerperError) -> Duration {
+            error
+                .retry_after()
+                .map(|delay| delay.min(self.max_delay))
+                .unwrap_or_else(|| self.delay_for(attempt))
+        }
+
+        /// Reports a retry to the configured callback, if any
+        pub(crate) fn notify(&self, event: &RetryEvent<'_>) {
+            if let Some(hook) = &self.on_retry {
+                hook(event);
+            }
+        }
     }
 
     impl Default for RetryConfig {
@@ -262,8 +423,35 @@ pub mod retry {
         }
     }
 
+    impl fmt::Debug for RetryConfig {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("RetryConfig")
+                .field("max_attempts", &self.max_attempts)
+                .field("initial_delay", &self.initial_delay)
+                .field("backoff_multiplier", &self.backoff_multiplier)
+                .field("max_delay", &self.max_delay)
+                .field("jitter", &self.jitter)
+                .field("on_retry", &self.on_retry.is_some())
+                .finish()
+        }
+    }
+
+    /// Gets a pseudo-random number in `0.0..1.0` for jitter
+    fn random_fraction() -> f64 {
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u128(
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_nanos()),
+        );
+        (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
     /// Executes a function with retry logic
     ///
+    /// Errors that are not transient (see [`SerperError::is_retryable`]) are
+    /// returned immediately.
+    ///
     /// # Arguments
     ///
     /// * `config` - Retry configuration
@@ -278,29 +466,29 @@ pub mod retry {
         Fut: std::future::Future<Output = std::result::Result<T, E>>,
         E: Into<SerperError>,
     {
-        let mut last_error = None;
-        let mut delay = config.initial_delay;
+        let max_attempts = config.max_attempts.max(1);
+        let mut attempt = 1;
 
-        for attempt in 0..config.max_attempts {
-            match operation().await {
+        loop {
+            let error = match operation().await {
                 Ok(result) => return Ok(result),
-                Err(error) => {
-                    last_error = Some(error.into());
-
-                    if attempt + 1 < config.max_attempts {
-                        sleep(delay).await;
-                        delay = std::cmp::min(
-                            Duration::from_millis(
-                                (delay.as_millis() as f64 * config.backoff_multiplier) as u64,
-                            ),
-                            config.max_delay,
-                        );
-                    }
-                }
+                Err(error) => error.into(),
+            };
+
+            if attempt >= max_attempts || !error.is_retryable() {
+                return Err(error);
             }
-        }
 
-        Err(last_error.unwrap_or_else(|| SerperError::config_error("Unknown retry error")))
+            let delay = config.delay_after(attempt, &error);
+            config.notify(&RetryEvent {
+                attempt,
+                max_attempts,
+                delay,
+                error: &error,
+            });
+            sleep(delay).await;
+            attempt += 1;
+        }
     }
 }
 
@@ -338,6 +526,28 @@ mod tests {
             );
             assert!(url::extract_domain("not-a-url").is_err());
         }
+
+        #[test]
+        fn test_normalize_url() {
+            assert_eq!(
+                url::normalize_url("https://www.Example.com/docs/?utm_source=x&b=2&a=1#top")
+                    .unwrap(),
+                "example.com/docs?a=1&b=2"
+            );
+            assert_eq!(
+                url::normalize_url("http://example.com/docs?gclid=abc").unwrap(),
+                "example.com/docs"
+            );
+            assert_eq!(
+                url::normalize_url("https://example.com/").unwrap(),
+                "example.com"
+            );
+            assert_eq!(
+                url::normalize_url("http://example.com:8080/a").unwrap(),
+                "example.com:8080/a"
+            );
+            assert!(url::normalize_url("not-a-url").is_err());
+        }
     }
 
     mod string_tests {
@@ -392,7 +602,9 @@ mod tests {
     }
 
     mod retry_tests {
-        use crate::utils::retry::RetryConfig;
+        use crate::core::{Result, SerperError};
+        use crate::utils::retry::{RetryConfig, with_retry};
+        use std::sync::atomic::{AtomicUsize, Ordering};
         use std::time::Duration;
 
         #[test]
@@ -404,5 +616,58 @@ mod tests {
             assert_eq!(config.max_attempts, 5);
             assert_eq!(config.initial_delay, Duration::from_millis(50));
         }
+
+        #[test]
+        fn test_retry_delay_backoff_and_jitter() {
+            let config = RetryConfig::new()
+                .with_initial_delay(Duration::from_millis(100))
+                .with_max_delay(Duration::from_millis(300))
+                .with_jitter(0.0);
+
+            assert_eq!(config.delay_for(1), Duration::from_millis(100));
+            assert_eq!(config.delay_for(2), Duration::from_millis(200));
+            assert_eq!(config.delay_for(3), Duration::from_millis(300));
+
+            let config = config.with_jitter(0.5);
+            for attempt in 1..=5 {
+                let delay = config.delay_for(attempt);
+                assert!(delay >= Duration::from_millis(50) && delay <= Duration::from_millis(300));
+            }
+        }
+
+        #[test]
+        fn test_retry_after_takes_precedence() {
+            let config = RetryConfig::new().with_jitter(0.0);
+            let limited = SerperError::RateLimited {
+                retry_after: Some(Duration::from_secs(2)),
+            };
+
+            assert_eq!(config.delay_after(1, &limited), Duration::from_secs(2));
+            assert_eq!(
+                config.delay_after(1, &SerperError::RateLimited { retry_after: None }),
+                Duration::from_millis(100)
+            );
+
+            let hostile = SerperError::RateLimited {
+                retry_after: Some(Duration::from_secs(86_400)),
+            };
+            let config = config.with_max_delay(Duration::from_secs(5));
+            assert_eq!(config.delay_after(1, &hostile), Duration::from_secs(5));
+        }
+
+        #[tokio::test]
+        async fn test_with_retry_skips_permanent_errors() {
+            let calls = AtomicUsize::new(0);
+            let config = RetryConfig::new().with_initial_delay(Duration::ZERO);
+
+            let result: Result<()> = with_retry(config, || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err(SerperError::InvalidApiKey)
+            })
+            .await;
+
+            assert!(result.is_err());
+            assert_eq!(calls.load(Ordering::SeqCst), 1);
+        }
     }
 }
@@ -117,8 +117,8 @@ mod tests {
         let query = create_test_query_with_all_params();
         assert_eq!(query.q, "comprehensive test");
         assert_eq!(query.location, Some("Paris".to_string()));
-        assert_eq!(query.gl, Some("fr".to_string()));
-        assert_eq!(query.hl, Some("en".to_string()));
+        assert_eq!(query.gl, Some("fr".into()));
+        assert_eq!(query.hl, Some("en".into()));
         assert_eq!(query.page, Some(1));
         assert_eq!(query.num, Some(10));
     }
@@ -4,9 +4,20 @@ use common::{
     assert_search_response_valid, create_mock_search_response, create_test_query_with_all_params,
     create_test_service_with_base_url,
 };
+use futures::future::BoxFuture;
+use futures::{StreamExt, TryStreamExt};
 use mockito::{Matcher, Server};
 use serde_json::json;
+use serper_sdk::http::{
+    CancellationToken, CircuitBreakerConfig, CircuitState, HedgeConfig, KeyStrategy, Middleware,
+    RateLimiter, Request, RequestOptions, Response, Transport,
+};
+use serper_sdk::search::service::SearchServiceBuilder;
+use serper_sdk::search::{CacheConfig, PaginationLimits};
+use serper_sdk::utils::retry::RetryConfig;
 use serper_sdk::{SearchQuery, SearchService, SerperError};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 #[tokio::test]
 async fn test_end_to_end_search_flow() {
@@ -143,6 +154,505 @@ async fn test_multiple_search_integration() {
     mock3.assert_async().await;
 }
 
+#[tokio::test]
+async fn test_batch_search_integration() {
+    let mut server = Server::new_async().await;
+
+    let first_chunk = server
+        .mock("POST", "/search")
+        .match_header("X-API-KEY", "batch-key")
+        .match_body(Matcher::JsonString(
+            json!([{"q": "rust"}, {"q": "go"}]).to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!([
+                {"organic": [{"title": "Rust", "link": "https://rust-lang.org", "position": 1}]},
+                {"organic": [{"title": "Go", "link": "https://go.dev", "position": 1}]}
+            ])
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let second_chunk = server
+        .mock("POST", "/search")
+        .match_header("X-API-KEY", "batch-key")
+        .match_body(Matcher::JsonString(json!([{"q": "python"}]).to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!([{"organic": [{"title": "", "link": "https://python.org", "position": 1}]}])
+                .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let client = create_test_service_with_base_url("batch-key".to_string(), server.url());
+
+    let queries = vec![
+        SearchQuery::new("rust".to_string()).unwrap(),
+        SearchQuery::new("invalid".to_string())
+            .unwrap()
+            .with_page(0),
+        SearchQuery::new("go".to_string()).unwrap(),
+        SearchQuery::new("python".to_string()).unwrap(),
+    ];
+
+    let results = client.search_batch(&queries, Some(2)).await;
+    assert_eq!(results.len(), 4);
+
+    assert_eq!(
+        results[0].as_ref().unwrap().organic_results()[0].title,
+        "Rust"
+    );
+    assert!(matches!(results[1], Err(SerperError::Validation { .. })));
+    assert_eq!(
+        results[2].as_ref().unwrap().organic_results()[0].title,
+        "Go"
+    );
+    // Item-level validation failures only affect their own query
+    assert!(matches!(results[3], Err(SerperError::Validation { .. })));
+
+    first_chunk.assert_async().await;
+    second_chunk.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_batch_search_chunk_failure() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/search")
+        .with_status(500)
+        .create_async()
+        .await;
+
+    let client = create_test_service_with_base_url("batch-key".to_string(), server.url());
+
+    let queries = vec![
+        SearchQuery::new("one".to_string()).unwrap(),
+        SearchQuery::new("two".to_string()).unwrap(),
+    ];
+
+    let results = client.search_batch(&queries, None).await;
+    assert_eq!(results.len(), 2);
+    for result in &results {
+        match result {
+            Err(SerperError::Http { status, .. }) => assert_eq!(*status, 500),
+            other => panic!("Expected Http error, got {:?}", other),
+        }
+    }
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_concurrent_report_keeps_partial_results() {
+    let mut server = Server::new_async().await;
+
+    let ok_mock = server
+        .mock("POST", "/search")
+        .match_body(Matcher::Regex("\"q\":\"ok".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"organic": [{"title": "Ok", "link": "https://ok.com", "position": 1}]})
+                .to_string(),
+        )
+        .expect(3)
+        .create_async()
+        .await;
+
+    let failing_mock = server
+        .mock("POST", "/search")
+        .match_body(Matcher::JsonString(json!({"q": "fails"}).to_string()))
+        .with_status(500)
+        .create_async()
+        .await;
+
+    let client = create_test_service_with_base_url("report-key".to_string(), server.url());
+
+    let queries = vec![
+        SearchQuery::new("ok 1".to_string()).unwrap(),
+        SearchQuery::new("fails".to_string()).unwrap(),
+        SearchQuery::new("ok 2".to_string()).unwrap(),
+        SearchQuery::new("ok 3".to_string()).unwrap(),
+    ];
+
+    let report = client.search_concurrent_report(&queries, Some(2)).await;
+
+    assert_eq!(report.len(), 4);
+    assert_eq!(report.success_count(), 3);
+    assert_eq!(report.failure_count(), 1);
+
+    let (failed_query, error) = report.failures().next().unwrap();
+    assert_eq!(failed_query.query(), "fails");
+    assert!(error.is_api_error());
+
+    let results = report.into_results();
+    assert_eq!(results[0].0.query(), "ok 1");
+    assert!(results[0].1.is_ok());
+    assert!(results[1].1.is_err());
+
+    ok_mock.assert_async().await;
+    failing_mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_search_stream_yields_every_query() {
+    let mut server = Server::new_async().await;
+
+    let ok_mock = server
+        .mock("POST", "/search")
+        .match_body(Matcher::Regex("\"q\":\"stream".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(create_mock_search_response().to_string())
+        .expect(2)
+        .create_async()
+        .await;
+
+    let failing_mock = server
+        .mock("POST", "/search")
+        .match_body(Matcher::JsonString(json!({"q": "broken"}).to_string()))
+        .with_status(502)
+        .create_async()
+        .await;
+
+    let client = create_test_service_with_base_url("stream-key".to_string(), server.url());
+
+    let queries = vec![
+        SearchQuery::new("stream 1".to_string()).unwrap(),
+        SearchQuery::new("broken".to_string()).unwrap(),
+        SearchQuery::new("stream 2".to_string()).unwrap(),
+    ];
+
+    let mut results: Vec<_> = client.search_stream(&queries, Some(2)).collect().await;
+    results.sort_by_key(|(index, _)| *index);
+
+    let indices: Vec<usize> = results.iter().map(|(index, _)| *index).collect();
+    assert_eq!(indices, vec![0, 1, 2]);
+    assert!(results[0].1.is_ok());
+    assert!(results[1].1.is_err());
+    assert!(results[2].1.is_ok());
+
+    ok_mock.assert_async().await;
+    failing_mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_paginate_stops_on_empty_page() {
+    let mut server = Server::new_async().await;
+
+    let mut mocks = Vec::new();
+    for page in 1..=2 {
+        let mock = server
+            .mock("POST", "/search")
+            .match_body(Matcher::PartialJson(json!({"q": "paged", "page": page})))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(create_mock_search_response().to_string())
+            .create_async()
+            .await;
+        mocks.push(mock);
+    }
+    let empty_mock = server
+        .mock("POST", "/search")
+        .match_body(Matcher::PartialJson(json!({"q": "paged", "page": 3})))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("{}")
+        .create_async()
+        .await;
+
+    let client = create_test_service_with_base_url("paginate-key".to_string(), server.url());
+    let query = SearchQuery::new("paged".to_string()).unwrap();
+
+    let results: Vec<_> = client.paginate(&query).try_collect().await.unwrap();
+    let positions: Vec<u32> = results.iter().map(|result| result.position).collect();
+    assert_eq!(positions, vec![1, 2, 3, 4]);
+    assert_eq!(results[2].title, "Test Result 1");
+
+    for mock in mocks {
+        mock.assert_async().await;
+    }
+    empty_mock.assert_async().await;
+
+    // A result limit stops mid-page without requesting further pages
+    let limits = PaginationLimits::unlimited().with_max_results(3);
+    let results: Vec<_> = client
+        .paginate_with_limits(&query, limits)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[2].position, 3);
+
+    // Starting from a later page keeps positions absolute
+    let query = query.with_page(2);
+    let results: Vec<_> = client.paginate(&query).try_collect().await.unwrap();
+    let positions: Vec<u32> = results.iter().map(|result| result.position).collect();
+    assert_eq!(positions, vec![11, 12]);
+}
+
+#[tokio::test]
+async fn test_retry_policy_retries_transient_failures() {
+    let mut server = Server::new_async().await;
+
+    let unavailable_mock = server
+        .mock("POST", "/search")
+        .with_status(503)
+        .expect(2)
+        .create_async()
+        .await;
+    let ok_mock = server
+        .mock("POST", "/search")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(create_mock_search_response().to_string())
+        .create_async()
+        .await;
+
+    let attempts = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&attempts);
+    let retry = RetryConfig::new()
+        .with_max_attempts(3)
+        .with_initial_delay(Duration::from_millis(1))
+        .on_retry(move |event| {
+            recorded
+                .lock()
+                .unwrap()
+                .push((event.attempt, event.error.to_string()))
+        });
+
+    let service = SearchServiceBuilder::new()
+        .api_key("retry-key")
+        .base_url(server.url())
+        .retry(retry)
+        .build()
+        .unwrap();
+
+    let response = service.search_simple("retry me").await.unwrap();
+    assert_search_response_valid(&response);
+
+    let attempts = attempts.lock().unwrap().clone();
+    assert_eq!(attempts.len(), 2);
+    assert_eq!(attempts[0].0, 1);
+    assert_eq!(attempts[1].0, 2);
+    assert!(attempts[0].1.contains("503"));
+
+    unavailable_mock.assert_async().await;
+    ok_mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_retry_policy_skips_permanent_failures() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/search")
+        .with_status(400)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let service = SearchServiceBuilder::new()
+        .api_key("retry-key")
+        .base_url(server.url())
+        .retry(RetryConfig::new().with_initial_delay(Duration::from_millis(1)))
+        .build()
+        .unwrap();
+
+    assert!(service.search_simple("bad request").await.is_err());
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_rate_limit_honours_retry_after() {
+    let mut server = Server::new_async().await;
+
+    let limited_mock = server
+        .mock("POST", "/search")
+        .with_status(429)
+        .with_header("Retry-After", "0")
+        .expect(1)
+        .create_async()
+        .await;
+    let ok_mock = server
+        .mock("POST", "/search")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(create_mock_search_response().to_string())
+        .create_async()
+        .await;
+
+    let delays = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&delays);
+    // The advised wait replaces the (much longer) backoff delay
+    let retry = RetryConfig::new()
+        .with_initial_delay(Duration::from_secs(30))
+        .on_retry(move |event| {
+            assert!(event.error.is_rate_limited());
+            recorded.lock().unwrap().push(event.delay);
+        });
+
+    let service = SearchServiceBuilder::new()
+        .api_key("rate-key")
+        .base_url(server.url())
+        .retry(retry)
+        .build()
+        .unwrap();
+
+    assert!(service.search_simple("throttled").await.is_ok());
+    assert_eq!(*delays.lock().unwrap(), vec![Duration::ZERO]);
+
+    limited_mock.assert_async().await;
+    ok_mock.assert_async().await;
+
+    // Without a retry policy the rate limit surfaces as a distinct error
+    let limited_mock = server
+        .mock("POST", "/search")
+        .with_status(429)
+        .with_header("Retry-After", "7")
+        .create_async()
+        .await;
+    let client = create_test_service_with_base_url("rate-key".to_string(), server.url());
+    match client.search_simple("throttled").await {
+        Err(SerperError::RateLimited { retry_after }) => {
+            assert_eq!(retry_after, Some(Duration::from_secs(7)));
+        }
+        other => panic!("Expected RateLimited error, got {:?}", other),
+    }
+    limited_mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_http_error_keeps_status_body_and_request_id() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/search")
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_header("x-request-id", "req-42")
+        .with_body(json!({"message": "Query parameter is invalid", "statusCode": 400}).to_string())
+        .create_async()
+        .await;
+
+    let client = create_test_service_with_base_url("http-key".to_string(), server.url());
+
+    let error = client.search_simple("bad").await.unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "HTTP 400 Bad Request: Query parameter is invalid"
+    );
+    match error {
+        SerperError::Http {
+            status,
+            body,
+            request_id,
+        } => {
+            assert_eq!(status, 400);
+            assert!(body.unwrap().contains("statusCode"));
+            assert_eq!(request_id.as_deref(), Some("req-42"));
+        }
+        other => panic!("Expected Http error, got {:?}", other),
+    }
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_rate_limiter_shared_between_services() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/search")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(create_mock_search_response().to_string())
+        .expect(6)
+        .create_async()
+        .await;
+
+    let limiter = Arc::new(RateLimiter::new(20.0, 2).unwrap());
+    let build = || {
+        SearchServiceBuilder::new()
+            .api_key("rate-key")
+            .base_url(server.url())
+            .rate_limiter(Arc::clone(&limiter))
+            .build()
+            .unwrap()
+    };
+    let first = build();
+    let second = build();
+
+    let queries: Vec<SearchQuery> = (0..3)
+        .map(|i| SearchQuery::new(format!("limited {}", i)).unwrap())
+        .collect();
+
+    let started = Instant::now();
+    let (a, b) = tokio::join!(
+        first.search_concurrent(&queries, Some(3)),
+        second.search_concurrent(&queries, Some(3))
+    );
+    assert!(a.is_ok() && b.is_ok());
+
+    // Burst of 2, then 4 more requests at 20/s (50ms apart) across both services
+    assert!(started.elapsed() >= Duration::from_millis(190));
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_circuit_breaker_fails_fast_during_outage() {
+    let mut server = Server::new_async().await;
+
+    let outage_mock = server
+        .mock("POST", "/search")
+        .with_status(503)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let service = SearchServiceBuilder::new()
+        .api_key("breaker-key")
+        .base_url(server.url())
+        .circuit_breaker(
+            CircuitBreakerConfig::new()
+                .with_failure_threshold(2)
+                .with_open_duration(Duration::from_millis(50)),
+        )
+        .build()
+        .unwrap();
+
+    for _ in 0..2 {
+        let error = service.search_simple("outage").await.unwrap_err();
+        assert_eq!(error.status_code(), Some(503));
+    }
+    assert_eq!(service.circuit_state(), Some(CircuitState::Open));
+
+    // Requests are rejected without reaching the server
+    let error = service.search_simple("outage").await.unwrap_err();
+    assert!(error.is_circuit_open());
+    outage_mock.assert_async().await;
+
+    // After the open duration a probe is sent and its success closes the circuit
+    let ok_mock = server
+        .mock("POST", "/search")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(create_mock_search_response().to_string())
+        .create_async()
+        .await;
+    tokio::time::sleep(Duration::from_millis(60)).await;
+
+    assert!(service.search_simple("recovered").await.is_ok());
+    assert_eq!(service.circuit_state(), Some(CircuitState::Closed));
+    ok_mock.assert_async().await;
+}
+
 #[tokio::test]
 async fn test_error_propagation_through_modules() {
     // Test invalid API key error propagation
@@ -169,10 +679,8 @@ async fn test_error_propagation_through_modules() {
 
     assert!(result.is_err());
     match result.unwrap_err() {
-        SerperError::Api { message } => {
-            assert!(message.contains("403"));
-        }
-        _ => panic!("Expected Api error"),
+        SerperError::InvalidApiKey => {}
+        other => panic!("Expected InvalidApiKey error, got {:?}", other),
     }
 
     mock.assert_async().await;
@@ -254,3 +762,842 @@ fn test_module_public_api_completeness() {
     // This test ensures our public API exports are complete and accessible
     // If any required type is not public, this won't compile
 }
+
+/// Transport that records requests and replies with canned responses
+struct RecordingTransport {
+    requests: Mutex<Vec<Request>>,
+    responses: Mutex<Vec<Response>>,
+}
+
+impl Transport for RecordingTransport {
+    fn send(&self, request: Request) -> BoxFuture<'_, serper_sdk::core::Result<Response>> {
+        self.requests.lock().unwrap().push(request);
+        let response = self.responses.lock().unwrap().remove(0);
+        Box::pin(async move { Ok(response) })
+    }
+}
+
+#[tokio::test]
+async fn test_custom_transport_without_network() {
+    let transport = Arc::new(RecordingTransport {
+        requests: Mutex::new(Vec::new()),
+        responses: Mutex::new(vec![
+            Response::from_json(
+                200,
+                &json!({
+                    "organic": [{"title": "Rust", "link": "https://www.rust-lang.org", "position": 1}]
+                }),
+            )
+            .unwrap(),
+            Response::new(503, "unavailable"),
+        ]),
+    });
+
+    let service = SearchServiceBuilder::new()
+        .api_key("test-key")
+        .base_url("https://serper.test")
+        .transport(Arc::clone(&transport))
+        .build()
+        .unwrap();
+
+    let response = service.search_simple("rust").await.unwrap();
+    assert_eq!(response.organic_results().len(), 1);
+
+    let error = service.search_simple("go").await.unwrap_err();
+    assert_eq!(error.status_code(), Some(503));
+
+    let requests = transport.requests.lock().unwrap();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].url, "https://serper.test/search");
+    assert_eq!(requests[0].header("X-API-KEY"), Some("test-key"));
+    assert_eq!(
+        requests[0].json::<serde_json::Value>().unwrap()["q"],
+        "rust"
+    );
+}
+
+#[tokio::test]
+async fn test_custom_transport_keeps_transport_settings() {
+    let transport = Arc::new(RecordingTransport {
+        requests: Mutex::new(Vec::new()),
+        responses: Mutex::new(vec![
+            Response::new(503, "unavailable"),
+            Response::from_json(200, &json!({"organic": []})).unwrap(),
+        ]),
+    });
+    let service = SearchServiceBuilder::new()
+        .api_key("test-key")
+        .transport(Arc::clone(&transport))
+        .header("X-Trace", "on")
+        .retry(RetryConfig::new().with_initial_delay(Duration::ZERO))
+        .build()
+        .unwrap();
+
+    service.search_simple("rust").await.unwrap();
+    {
+        let requests = transport.requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].header("X-Trace"), Some("on"));
+    }
+
+    // A stalled attempt times out and the retry gets an answer
+    let transport = Arc::new(StallFirstTransport::default());
+    let service = SearchServiceBuilder::new()
+        .api_key("test-key")
+        .transport(Arc::clone(&transport))
+        .timeout(Duration::from_millis(50))
+        .retry(RetryConfig::new().with_initial_delay(Duration::ZERO))
+        .build()
+        .unwrap();
+    service.search_simple("rust").await.unwrap();
+    assert_eq!(*transport.calls.lock().unwrap(), 2);
+
+    let service = SearchServiceBuilder::new()
+        .api_key("test-key")
+        .transport(StallFirstTransport::default())
+        .timeout(Duration::from_millis(50))
+        .build()
+        .unwrap();
+    let error = service.search_simple("rust").await.unwrap_err();
+    assert!(error.is_timeout(), "{:?}", error);
+}
+
+/// Middleware counting successful and failed calls
+#[derive(Default)]
+struct CallCounter {
+    succeeded: Mutex<u32>,
+    failed: Mutex<u32>,
+}
+
+impl Middleware for CallCounter {
+    fn after_response(
+        &self,
+        _: &Request,
+        _: &mut Response,
+        _: Duration,
+    ) -> serper_sdk::core::Result<()> {
+        *self.succeeded.lock().unwrap() += 1;
+        Ok(())
+    }
+
+    fn on_error(&self, _: &Request, _: &SerperError, _: Duration) {
+        *self.failed.lock().unwrap() += 1;
+    }
+}
+
+#[tokio::test]
+async fn test_middleware_adds_headers_and_observes_calls() {
+    let mut server = Server::new_async().await;
+    let ok = server
+        .mock("POST", "/search")
+        .match_header("x-correlation-id", "req-42")
+        .match_header("x-api-key", "test-key")
+        .with_status(200)
+        .with_body(json!({"organic": []}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+    let failing = server
+        .mock("POST", "/search")
+        .with_status(500)
+        .create_async()
+        .await;
+
+    let counter = Arc::new(CallCounter::default());
+    let timings = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&timings);
+
+    let service = SearchServiceBuilder::new()
+        .api_key("test-key")
+        .base_url(server.url())
+        .before_request(|request| {
+            request
+                .headers
+                .insert("X-Correlation-Id".to_string(), "req-42".to_string());
+            Ok(())
+        })
+        .after_response(move |_, _, elapsed| {
+            recorded.lock().unwrap().push(elapsed);
+            Ok(())
+        })
+        .middleware(Arc::clone(&counter))
+        .build()
+        .unwrap();
+
+    assert!(service.search_simple("rust").await.is_ok());
+    assert!(service.search_simple("rust").await.is_err());
+
+    ok.assert_async().await;
+    failing.assert_async().await;
+    assert_eq!(timings.lock().unwrap().len(), 1);
+    assert_eq!(*counter.succeeded.lock().unwrap(), 1);
+    assert_eq!(*counter.failed.lock().unwrap(), 1);
+}
+
+#[tokio::test]
+async fn test_cache_answers_repeated_queries() {
+    let mut server = Server::new_async().await;
+    let single = server
+        .mock("POST", "/search")
+        .match_body(Matcher::PartialJson(json!({"q": "rust lang"})))
+        .with_status(200)
+        .with_body(json!({"organic": [], "credits": 1}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+    let batch = server
+        .mock("POST", "/search")
+        .match_body(Matcher::Json(json!([{"q": "go"}])))
+        .with_status(200)
+        .with_body(json!([{"organic": []}]).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let service = SearchServiceBuilder::new()
+        .api_key("test-key")
+        .base_url(server.url())
+        .cache(CacheConfig::new().with_ttl(Duration::from_secs(60)))
+        .build()
+        .unwrap();
+
+    service.search_simple("rust lang").await.unwrap();
+    service.search_simple("  rust  lang ").await.unwrap();
+
+    // Only the uncached query is sent in the batch
+    let queries = vec![
+        SearchQuery::new("rust lang".to_string()).unwrap(),
+        SearchQuery::new("go".to_string()).unwrap(),
+    ];
+    let results = service.search_batch(&queries, Some(10)).await;
+    assert!(results.iter().all(Result::is_ok));
+
+    single.assert_async().await;
+    batch.assert_async().await;
+
+    let stats = service.cache().unwrap().stats();
+    assert_eq!(stats.hits, 2);
+    assert_eq!(stats.entries, 2);
+}
+
+#[cfg(feature = "disk-cache")]
+#[tokio::test]
+async fn test_disk_cache_survives_new_service() {
+    use serper_sdk::search::DiskCache;
+
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("POST", "/search")
+        .with_status(200)
+        .with_body(json!({"organic": []}).to_string())
+        .expect(2)
+        .create_async()
+        .await;
+
+    let dir = std::env::temp_dir().join(format!("serper-sdk-it-cache-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let build = || {
+        SearchServiceBuilder::new()
+            .api_key("test-key")
+            .base_url(server.url())
+            .disk_cache(DiskCache::open(&dir, Duration::from_secs(3600)).unwrap())
+            .build()
+            .unwrap()
+    };
+
+    let query = SearchQuery::new("nightly batch".to_string()).unwrap();
+    build().search(&query).await.unwrap();
+
+    // A new service, as after a restart, reuses the stored response
+    let restarted = build();
+    restarted.search(&query).await.unwrap();
+    assert_eq!(restarted.disk_cache().unwrap().stats().hits, 1);
+
+    // A zero max-age forces a fresh request
+    restarted
+        .search_with_max_age(&query, Duration::ZERO)
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+/// Transport answering every request after a delay, counting requests
+#[derive(Default)]
+struct SlowTransport {
+    calls: Mutex<u32>,
+}
+
+impl Transport for SlowTransport {
+    fn send(&self, request: Request) -> BoxFuture<'_, serper_sdk::core::Result<Response>> {
+        *self.calls.lock().unwrap() += 1;
+        Box::pin(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            let result = json!({
+                "organic": [{"title": "Rust", "link": "https://www.rust-lang.org", "position": 1}]
+            });
+            // Batch requests get one result per query
+            match request.json::<serde_json::Value>()? {
+                serde_json::Value::Array(queries) => {
+                    Response::from_json(200, &vec![result; queries.len()])
+                }
+                _ => Response::from_json(200, &result),
+            }
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_concurrent_duplicate_searches_are_coalesced() {
+    let transport = Arc::new(SlowTransport::default());
+    let service = SearchServiceBuilder::new()
+        .api_key("test-key")
+        .base_url("https://serper.test")
+        .transport(Arc::clone(&transport))
+        .coalesce_requests(true)
+        .build()
+        .unwrap();
+    assert!(service.coalesces_requests());
+
+    let query = SearchQuery::new("rust".to_string()).unwrap();
+    let results = futures::future::join_all((0..5).map(|_| service.search(&query))).await;
+    assert!(
+        results
+            .iter()
+            .all(|result| result.as_ref().unwrap().organic_results().len() == 1)
+    );
+    assert_eq!(*transport.calls.lock().unwrap(), 1);
+
+    // Queries differing only in case are different searches
+    let upper = SearchQuery::new("rust OR go".to_string()).unwrap();
+    let lower = SearchQuery::new("rust or go".to_string()).unwrap();
+    let (upper, lower) =
+        futures::future::join(service.search(&upper), service.search(&lower)).await;
+    assert!(upper.is_ok() && lower.is_ok());
+    assert_eq!(*transport.calls.lock().unwrap(), 3);
+
+    let uncoalesced = SearchServiceBuilder::new()
+        .api_key("test-key")
+        .base_url("https://serper.test")
+        .transport(Arc::clone(&transport))
+        .build()
+        .unwrap();
+    assert!(!uncoalesced.coalesces_requests());
+    futures::future::join_all((0..3).map(|_| uncoalesced.search(&query))).await;
+    assert_eq!(*transport.calls.lock().unwrap(), 6);
+}
+
+#[tokio::test]
+async fn test_request_options_timeout_and_cancellation() {
+    let transport = Arc::new(RecordingTransport {
+        requests: Mutex::new(Vec::new()),
+        responses: Mutex::new(vec![Response::from_json(200, &json!({})).unwrap()]),
+    });
+    let service = SearchServiceBuilder::new()
+        .api_key("test-key")
+        .transport(Arc::clone(&transport))
+        .build()
+        .unwrap();
+
+    let query = SearchQuery::new("rust".to_string()).unwrap();
+    let options = RequestOptions::new().with_timeout(Duration::from_secs(2));
+    service.search_with_options(&query, options).await.unwrap();
+    assert_eq!(
+        transport.requests.lock().unwrap()[0].timeout,
+        Some(Duration::from_secs(2))
+    );
+
+    let slow = Arc::new(SlowTransport::default());
+    let service = SearchServiceBuilder::new()
+        .api_key("test-key")
+        .transport(Arc::clone(&slow))
+        .build()
+        .unwrap();
+
+    let cancel = CancellationToken::new();
+    let options = RequestOptions::new().with_cancel(cancel.clone());
+    let started = Instant::now();
+    let (result, _) = tokio::join!(service.search_with_options(&query, options), async {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        cancel.cancel();
+    });
+    assert!(result.unwrap_err().is_cancelled());
+    assert!(started.elapsed() < Duration::from_millis(50));
+}
+
+/// Transport that always answers 503
+struct UnavailableTransport;
+
+impl Transport for UnavailableTransport {
+    fn send(&self, _: Request) -> BoxFuture<'_, serper_sdk::core::Result<Response>> {
+        Box::pin(async { Ok(Response::new(503, "unavailable")) })
+    }
+}
+
+#[tokio::test]
+async fn test_request_options_timeout_covers_retries() {
+    let service = SearchServiceBuilder::new()
+        .api_key("test-key")
+        .transport(UnavailableTransport)
+        .retry(
+            RetryConfig::new()
+                .with_max_attempts(100)
+                .with_initial_delay(Duration::from_millis(50))
+                .with_backoff_multiplier(1.0)
+                .with_jitter(0.0),
+        )
+        .build()
+        .unwrap();
+
+    let query = SearchQuery::new("rust".to_string()).unwrap();
+    let options = RequestOptions::new().with_timeout(Duration::from_millis(200));
+    let started = Instant::now();
+    let error = service
+        .search_with_options(&query, options)
+        .await
+        .unwrap_err();
+    assert!(error.is_timeout(), "{:?}", error);
+    // 100 attempts 50ms apart would take about 5 seconds
+    assert!(started.elapsed() < Duration::from_secs(2));
+}
+
+#[tokio::test]
+async fn test_report_elapsed_excludes_queue_time() {
+    let service = SearchServiceBuilder::new()
+        .api_key("test-key")
+        .transport(SlowTransport::default())
+        .build()
+        .unwrap();
+    let queries: Vec<SearchQuery> = ["a", "b", "c"]
+        .iter()
+        .map(|q| SearchQuery::new(q.to_string()).unwrap())
+        .collect();
+
+    // One slot: each 50ms request waits for the previous ones
+    let report = service
+        .search_concurrent_report_with_options(&queries, Some(1), RequestOptions::new())
+        .await;
+
+    assert!(report.items.iter().all(|item| item.result.is_ok()));
+    // The requests ran one after another inside the report's time, so their
+    // own times only fit in it if queue time is left out
+    let total: Duration = report.items.iter().map(|item| item.elapsed).sum();
+    assert!(
+        total <= report.elapsed,
+        "{:?} > {:?}",
+        total,
+        report.elapsed
+    );
+}
+
+#[tokio::test]
+async fn test_concurrent_searches_with_zero_limit_run_one_at_a_time() {
+    let service = SearchServiceBuilder::new()
+        .api_key("test-key")
+        .transport(SlowTransport::default())
+        .build()
+        .unwrap();
+    let queries: Vec<SearchQuery> = ["a", "b"]
+        .iter()
+        .map(|q| SearchQuery::new(q.to_string()).unwrap())
+        .collect();
+
+    let run = async {
+        let report = service.search_concurrent_report(&queries, Some(0)).await;
+        assert_eq!(report.success_count(), 2);
+        let responses = service.search_concurrent(&queries, Some(0)).await.unwrap();
+        assert_eq!(responses.len(), 2);
+    };
+    tokio::time::timeout(Duration::from_secs(5), run)
+        .await
+        .expect("a zero concurrency limit must not hang");
+}
+
+#[tokio::test]
+async fn test_cancellation_aborts_remaining_batch_chunks() {
+    let transport = Arc::new(SlowTransport::default());
+    let service = SearchServiceBuilder::new()
+        .api_key("test-key")
+        .transport(Arc::clone(&transport))
+        .build()
+        .unwrap();
+    let queries: Vec<SearchQuery> = ["a", "b", "c", "d"]
+        .iter()
+        .map(|q| SearchQuery::new(q.to_string()).unwrap())
+        .collect();
+
+    // Chunks take 50ms each: the first completes, the second is in flight
+    let cancel = CancellationToken::new();
+    let options = RequestOptions::new().with_cancel(cancel.clone());
+    let (results, _) = tokio::join!(
+        service.search_batch_with_options(&queries, Some(1), options),
+        async {
+            tokio::time::sleep(Duration::from_millis(75)).await;
+            cancel.cancel();
+        }
+    );
+
+    assert!(results[0].is_ok());
+    assert!(
+        results[1..]
+            .iter()
+            .all(|result| matches!(result, Err(SerperError::Cancelled)))
+    );
+    assert_eq!(*transport.calls.lock().unwrap(), 2);
+}
+
+/// Transport that stalls on its first request and answers the rest at once
+#[derive(Default)]
+struct StallFirstTransport {
+    calls: Mutex<u32>,
+}
+
+impl Transport for StallFirstTransport {
+    fn send(&self, _: Request) -> BoxFuture<'_, serper_sdk::core::Result<Response>> {
+        let call = {
+            let mut calls = self.calls.lock().unwrap();
+            *calls += 1;
+            *calls
+        };
+        Box::pin(async move {
+            if call == 1 {
+                tokio::time::sleep(Duration::from_secs(10)).await;
+            }
+            Response::from_json(200, &json!({"organic": []}))
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_hedging_answers_stalled_search() {
+    let transport = Arc::new(StallFirstTransport::default());
+    let service = SearchServiceBuilder::new()
+        .api_key("test-key")
+        .transport(Arc::clone(&transport))
+        .hedging(
+            HedgeConfig::new()
+                .with_initial_delay(Duration::from_millis(20))
+                .with_max_hedges_per_minute(1),
+        )
+        .build()
+        .unwrap();
+
+    let started = Instant::now();
+    service.search_simple("rust").await.unwrap();
+    assert!(started.elapsed() < Duration::from_secs(1));
+    assert_eq!(*transport.calls.lock().unwrap(), 2);
+
+    let stats = service.hedge_stats().unwrap();
+    assert_eq!((stats.requests, stats.hedged, stats.hedge_wins), (1, 1, 1));
+}
+
+#[tokio::test]
+async fn test_hedged_copies_are_charged_to_the_budget() {
+    let hedging = HedgeConfig::new().with_initial_delay(Duration::from_millis(20));
+
+    // The abandoned first copy keeps its credit
+    let budget = Arc::new(serper_sdk::http::CreditBudget::new(10).unwrap());
+    let service = SearchServiceBuilder::new()
+        .api_key("test-key")
+        .transport(StallFirstTransport::default())
+        .hedging(hedging.clone())
+        .credit_budget(Arc::clone(&budget))
+        .build()
+        .unwrap();
+    service.search_simple("rust").await.unwrap();
+    assert_eq!(budget.spent(), 2);
+
+    // Without a credit left for it, the duplicate is not sent
+    let transport = Arc::new(StallFirstTransport::default());
+    let budget = Arc::new(serper_sdk::http::CreditBudget::new(1).unwrap());
+    let service = SearchServiceBuilder::new()
+        .api_key("test-key")
+        .transport(Arc::clone(&transport))
+        .hedging(hedging)
+        .credit_budget(Arc::clone(&budget))
+        .build()
+        .unwrap();
+    let cancel = CancellationToken::new();
+    let query = SearchQuery::new("rust".to_string()).unwrap();
+    let (result, _) = tokio::join!(
+        service.search_with_options(&query, RequestOptions::new().with_cancel(cancel.clone())),
+        async {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            cancel.cancel();
+        }
+    );
+    assert!(result.unwrap_err().is_cancelled());
+    assert_eq!(*transport.calls.lock().unwrap(), 1);
+    assert_eq!(budget.spent(), 1);
+}
+
+#[tokio::test]
+async fn test_key_pool_fails_over_from_exhausted_keys() {
+    let mut server = Server::new_async().await;
+
+    let rejected = server
+        .mock("POST", "/search")
+        .match_header("X-API-KEY", "revoked-key")
+        .with_status(401)
+        .expect(1)
+        .create_async()
+        .await;
+    let out_of_credits = server
+        .mock("POST", "/search")
+        .match_header("X-API-KEY", "empty-key")
+        .with_status(400)
+        .with_body(r#"{"message": "Not enough credits", "statusCode": 400}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let working = server
+        .mock("POST", "/search")
+        .match_header("X-API-KEY", "good-key")
+        .with_status(200)
+        .with_body(r#"{"organic": []}"#)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let service = SearchServiceBuilder::new()
+        .api_keys(
+            ["revoked-key", "empty-key", "good-key"],
+            KeyStrategy::Failover,
+        )
+        .base_url(server.url())
+        .build()
+        .unwrap();
+
+    service.search_simple("rust").await.unwrap();
+    service.search_simple("go").await.unwrap();
+
+    let pool = service.key_pool().unwrap();
+    assert_eq!(pool.available(), 1);
+    let stats = pool.stats();
+    assert!(stats[0].exhausted && stats[1].exhausted && !stats[2].exhausted);
+    assert_eq!(stats[2].requests, 2);
+
+    rejected.assert_async().await;
+    out_of_credits.assert_async().await;
+    working.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_key_pool_reports_last_error_when_all_keys_fail() {
+    let mut server = Server::new_async().await;
+    let _rejected = server
+        .mock("POST", "/search")
+        .with_status(403)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let service = SearchServiceBuilder::new()
+        .api_keys(["key-a", "key-b"], KeyStrategy::RoundRobin)
+        .base_url(server.url())
+        .build()
+        .unwrap();
+
+    let error = service.search_simple("rust").await.unwrap_err();
+    let SerperError::KeyPoolExhausted {
+        keys: 2,
+        last_error: Some(last_error),
+    } = error
+    else {
+        panic!("expected an exhausted pool, got {:?}", error);
+    };
+    assert!(last_error.is_auth_error());
+
+    let error = service.search_simple("rust").await.unwrap_err();
+    assert!(error.is_key_pool_exhausted());
+}
+
+#[tokio::test]
+async fn test_key_pool_keeps_keys_on_unrelated_errors() {
+    let mut server = Server::new_async().await;
+    let bad_request = server
+        .mock("POST", "/search")
+        .with_status(400)
+        .with_body(r#"{"message": "invalid credits param", "statusCode": 400}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let service = SearchServiceBuilder::new()
+        .api_keys(["key-a", "key-b"], KeyStrategy::Failover)
+        .base_url(server.url())
+        .build()
+        .unwrap();
+
+    let error = service.search_simple("rust").await.unwrap_err();
+    assert_eq!(error.status_code(), Some(400));
+    assert_eq!(service.key_pool().unwrap().available(), 2);
+    bad_request.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_credit_budget_stops_spending() {
+    let mut server = Server::new_async().await;
+    let batch = server
+        .mock("POST", "/search")
+        .match_body(Matcher::Regex(r"^\[".to_string()))
+        .with_status(200)
+        .with_body(r#"[{"organic": [], "credits": 1}, {"organic": []}]"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let search = server
+        .mock("POST", "/search")
+        .with_status(200)
+        .with_body(r#"{"organic": [], "credits": 2}"#)
+        .expect(2)
+        .create_async()
+        .await;
+    let budget = Arc::new(serper_sdk::http::CreditBudget::new(5).unwrap());
+    let service = SearchServiceBuilder::new()
+        .api_key("test-key")
+        .base_url(server.url())
+        .credit_budget(Arc::clone(&budget))
+        .build()
+        .unwrap();
+
+    let queries = vec![
+        SearchQuery::new("a".to_string()).unwrap(),
+        SearchQuery::new("b".to_string()).unwrap(),
+    ];
+    let results = service.search_batch(&queries, None).await;
+    assert!(results.iter().all(Result::is_ok));
+    assert_eq!(budget.spent(), 2);
+
+    let response = service.search_simple("rust").await.unwrap();
+    assert_eq!(response.credits, Some(2));
+    service.search_simple("go").await.unwrap();
+    assert_eq!(budget.spent(), 6);
+
+    let error = service.search_simple("zig").await.unwrap_err();
+    assert!(error.is_budget_exceeded());
+    assert_eq!(budget.remaining(), 0);
+
+    search.assert_async().await;
+    batch.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_credit_budget_shrinks_batches_and_limits_concurrency() {
+    let transport = Arc::new(SlowTransport::default());
+    let budget = Arc::new(serper_sdk::http::CreditBudget::new(3).unwrap());
+    let service = SearchServiceBuilder::new()
+        .api_key("test-key")
+        .transport(Arc::clone(&transport))
+        .credit_budget(Arc::clone(&budget))
+        .build()
+        .unwrap();
+    let queries: Vec<SearchQuery> = ["a", "b", "c", "d", "e"]
+        .iter()
+        .map(|q| SearchQuery::new(q.to_string()).unwrap())
+        .collect();
+
+    // The chunk of 5 is cut to the 3 credits left
+    let results = service.search_batch(&queries, Some(10)).await;
+    assert!(results[..3].iter().all(Result::is_ok));
+    assert!(
+        results[3..]
+            .iter()
+            .all(|result| result.as_ref().unwrap_err().is_budget_exceeded())
+    );
+    assert_eq!(*transport.calls.lock().unwrap(), 1);
+    assert_eq!(budget.spent(), 3);
+
+    // Concurrent searches reserve their credit before sending
+    budget.reset();
+    let report = service.search_concurrent_report(&queries, Some(5)).await;
+    assert_eq!(
+        report
+            .items
+            .iter()
+            .filter(|item| item.result.is_ok())
+            .count(),
+        3
+    );
+    assert_eq!(*transport.calls.lock().unwrap(), 4);
+    assert_eq!(budget.spent(), 3);
+}
+
+#[tokio::test]
+async fn test_credit_budget_charges_by_result_count() {
+    let transport = Arc::new(SlowTransport::default());
+    let budget = Arc::new(serper_sdk::http::CreditBudget::new(3).unwrap());
+    let service = SearchServiceBuilder::new()
+        .api_key("test-key")
+        .transport(Arc::clone(&transport))
+        .credit_budget(Arc::clone(&budget))
+        .build()
+        .unwrap();
+    let large = SearchQuery::new("rust".to_string())
+        .unwrap()
+        .with_num_results(20);
+
+    // More than 10 results cost two credits
+    service.search(&large).await.unwrap();
+    assert_eq!(budget.spent(), 2);
+    let error = service
+        .search(&large.clone().with_page(2))
+        .await
+        .unwrap_err();
+    assert!(error.is_budget_exceeded());
+    assert_eq!(*transport.calls.lock().unwrap(), 1);
+
+    // The batch chunk stops before the query that no longer fits
+    budget.reset();
+    let small = SearchQuery::new("go".to_string()).unwrap();
+    let queries = vec![small.clone(), large.clone(), small.clone(), large];
+    let results = service.search_batch(&queries, Some(10)).await;
+    assert!(results[..2].iter().all(Result::is_ok));
+    assert!(
+        results[2..]
+            .iter()
+            .all(|result| result.as_ref().unwrap_err().is_budget_exceeded())
+    );
+    assert_eq!(budget.spent(), 3);
+}
+
+#[tokio::test]
+async fn test_credit_budget_refunds_only_unsent_requests() {
+    let budget = Arc::new(serper_sdk::http::CreditBudget::new(10).unwrap());
+    let query = SearchQuery::new("rust".to_string()).unwrap();
+
+    // An answer that cannot be read was still charged
+    let service = SearchServiceBuilder::new()
+        .api_key("test-key")
+        .transport(RecordingTransport {
+            requests: Mutex::new(Vec::new()),
+            responses: Mutex::new(vec![Response::new(200, "not json")]),
+        })
+        .credit_budget(Arc::clone(&budget))
+        .build()
+        .unwrap();
+    assert!(service.search(&query).await.is_err());
+    assert_eq!(budget.spent(), 1);
+
+    // So may an error status
+    let service = SearchServiceBuilder::new()
+        .api_key("test-key")
+        .transport(UnavailableTransport)
+        .credit_budget(Arc::clone(&budget))
+        .build()
+        .unwrap();
+    assert!(service.search(&query).await.is_err());
+    assert_eq!(budget.spent(), 2);
+
+    // A request that could not connect was never charged
+    let service = SearchServiceBuilder::new()
+        .api_key("test-key")
+        .base_url("http://127.0.0.1:1")
+        .credit_budget(Arc::clone(&budget))
+        .build()
+        .unwrap();
+    assert!(service.search(&query).await.is_err());
+    assert_eq!(budget.spent(), 2);
+}